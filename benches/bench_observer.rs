@@ -0,0 +1,50 @@
+//! Compares the per-event cost of `#[async_trait]` observers and native `async fn` observers
+//! when called directly. Through a `Subject` both go through the same boxed `Observer` call,
+//! so there is nothing to compare there.
+//!
+//! Run with `cargo bench --features native-async`.
+
+use async_observer::{NativeObserver, Observer, ObserverError};
+use async_trait::async_trait;
+use criterion::async_executor::FuturesExecutor;
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[derive(Default)]
+struct BoxedCounter(AtomicU64);
+
+#[async_trait]
+impl Observer<u64> for BoxedCounter {
+    async fn update(&self, data: &u64) -> Result<(), ObserverError> {
+        self.0.fetch_add(*data, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct NativeCounter(AtomicU64);
+
+impl NativeObserver<u64> for NativeCounter {
+    async fn update(&self, data: &u64) -> Result<(), ObserverError> {
+        self.0.fetch_add(*data, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+fn direct_calls(c: &mut Criterion) {
+    let mut group = c.benchmark_group("direct update");
+    let boxed = BoxedCounter::default();
+    group.bench_function("async_trait", |b| {
+        b.to_async(FuturesExecutor)
+            .iter(|| async { Observer::update(&boxed, black_box(&1)).await });
+    });
+    let native = NativeCounter::default();
+    group.bench_function("native", |b| {
+        b.to_async(FuturesExecutor)
+            .iter(|| async { NativeObserver::update(&native, black_box(&1)).await });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, direct_calls);
+criterion_main!(benches);
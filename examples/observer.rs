@@ -1,4 +1,4 @@
-use async_observer::{Observer, Subject};
+use async_observer::{Observer, ObserverError, Subject};
 use async_trait::async_trait;
 use std::sync::Arc;
 use tokio::time::{self, Duration};
@@ -10,8 +10,9 @@ struct LoggerObserver;
 
 #[async_trait]
 impl Observer<String> for LoggerObserver {
-    async fn update(&self, data: &String) {
+    async fn update(&self, data: &String) -> Result<(), ObserverError> {
         info!("[Logger] Received message: \"{}\"", data);
+        Ok(())
     }
 }
 
@@ -20,10 +21,11 @@ struct DelayedObserver;
 
 #[async_trait]
 impl Observer<String> for DelayedObserver {
-    async fn update(&self, data: &String) {
+    async fn update(&self, data: &String) -> Result<(), ObserverError> {
         info!("[Delayed] Starting delayed update for: \"{}\"", data);
         time::sleep(Duration::from_millis(1500)).await;
         info!("[Delayed] Finished delayed update.");
+        Ok(())
     }
 }
 
@@ -32,8 +34,9 @@ struct ExplicitObserver;
 
 #[async_trait]
 impl Observer<String> for ExplicitObserver {
-    async fn update(&self, data: &String) {
+    async fn update(&self, data: &String) -> Result<(), ObserverError> {
         info!("[Explicit] Received message: \"{}\"", data);
+        Ok(())
     }
 }
 
@@ -55,16 +58,16 @@ async fn main() {
     let explicit_observer = Arc::new(ExplicitObserver);
 
     info!("Attaching three observers...");
-    let logger_handle = subject.attach(logger_observer);
-    let _delayed_handle = subject.attach(delayed_observer);
-    let explicit_handle = subject.attach(explicit_observer);
+    let logger_handle = subject.attach(logger_observer).await;
+    let _delayed_handle = subject.attach(delayed_observer).await;
+    let explicit_handle = subject.attach(explicit_observer).await;
 
     info!("Notifying all observers. All three should receive the event.");
     subject.notify(&String::from("First event")).await;
 
     // Explicitly detach the explicit observer using its handle.
     info!("\nExplicitly detaching the explicit observer...");
-    subject.detach(explicit_handle);
+    subject.detach(explicit_handle).await;
 
     info!("\nNotifying again. Only the logger and delayed observers should receive the event.");
     subject.notify(&String::from("Second event")).await;
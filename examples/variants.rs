@@ -0,0 +1,87 @@
+use async_observer::observers::variant::VariantObserver;
+use async_observer::{Observer, ObserverError, Subject};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::{Level, info};
+use tracing_subscriber::FmtSubscriber;
+
+/// The payload of a sign-up event.
+struct UserSignedUp {
+    name: String,
+}
+
+/// The payload of an order event.
+struct OrderPlaced {
+    total_cents: u64,
+}
+
+/// All events of the application, carried by a single subject.
+enum AppEvent {
+    UserSignedUp(UserSignedUp),
+    OrderPlaced(OrderPlaced),
+}
+
+/// An observer only interested in sign-ups.
+struct WelcomeObserver;
+
+#[async_trait]
+impl Observer<UserSignedUp> for WelcomeObserver {
+    async fn update(&self, data: &UserSignedUp) -> Result<(), ObserverError> {
+        info!("[Welcome] Welcome aboard, {}!", data.name);
+        Ok(())
+    }
+}
+
+/// An observer only interested in orders.
+struct BillingObserver;
+
+#[async_trait]
+impl Observer<OrderPlaced> for BillingObserver {
+    async fn update(&self, data: &OrderPlaced) -> Result<(), ObserverError> {
+        info!("[Billing] Charging {} cents.", data.total_cents);
+        Ok(())
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let subscriber = FmtSubscriber::builder()
+        .with_max_level(Level::INFO)
+        .finish();
+
+    tracing::subscriber::set_global_default(subscriber).expect("setting default subscriber failed");
+
+    let subject = Subject::<AppEvent>::new();
+
+    // Each observer registers interest in exactly one variant.
+    let _welcome_handle = subject
+        .attach(Arc::new(VariantObserver::new(
+            Arc::new(WelcomeObserver),
+            |event: &AppEvent| match event {
+                AppEvent::UserSignedUp(signup) => Some(signup),
+                AppEvent::OrderPlaced(_) => None,
+            },
+        )))
+        .await;
+    let _billing_handle = subject
+        .attach(Arc::new(VariantObserver::new(
+            Arc::new(BillingObserver),
+            |event: &AppEvent| match event {
+                AppEvent::OrderPlaced(order) => Some(order),
+                AppEvent::UserSignedUp(_) => None,
+            },
+        )))
+        .await;
+
+    info!("Notifying a sign-up. Only the welcome observer should fire.");
+    subject
+        .notify(&AppEvent::UserSignedUp(UserSignedUp {
+            name: String::from("Ada"),
+        }))
+        .await;
+
+    info!("\nNotifying an order. Only the billing observer should fire.");
+    subject
+        .notify(&AppEvent::OrderPlaced(OrderPlaced { total_cents: 4200 }))
+        .await;
+}
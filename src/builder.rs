@@ -0,0 +1,103 @@
+//! A builder for configuring a `Subject` in one place.
+
+use crate::history::History;
+use crate::{NotifyMode, Subject, SubjectOptions};
+#[cfg(feature = "tokio")]
+use std::time::Duration;
+
+/// The history capacity and the function cloning events into the history buffer.
+type HistoryConfig<T> = (usize, fn(&T) -> T);
+
+/// A builder for a configured [`Subject`].
+///
+/// [`Subject::new`] remains the zero-configuration path; the builder collects the optional
+/// settings and creates the `Subject` with [`SubjectBuilder::build`]. The builder is `Clone`
+/// and `build` borrows it, so a base configuration can be reused for several subjects.
+pub struct SubjectBuilder<T> {
+    history: Option<HistoryConfig<T>>,
+    notify_mode: NotifyMode,
+    #[cfg(feature = "tokio")]
+    default_timeout: Option<Duration>,
+    max_observers: Option<usize>,
+}
+
+impl<T> SubjectBuilder<T> {
+    /// Creates a builder with the default configuration: no history, concurrent delivery,
+    /// no timeout and no observer limit.
+    pub const fn new() -> Self {
+        Self {
+            history: None,
+            notify_mode: NotifyMode::Concurrent,
+            #[cfg(feature = "tokio")]
+            default_timeout: None,
+            max_observers: None,
+        }
+    }
+
+    /// Sets how observers are run when an event is notified.
+    pub const fn notify_mode(mut self, notify_mode: NotifyMode) -> Self {
+        self.notify_mode = notify_mode;
+        self
+    }
+
+    /// Sets the time each observer's `update` may take before it is abandoned.
+    ///
+    /// An update that exceeds the timeout is dropped and reported as
+    /// `ObserverError::TimedOut`.
+    /// Timeouts rely on the Tokio timer, so the `Subject` must then be notified from within
+    /// a Tokio runtime.
+    #[cfg(feature = "tokio")]
+    pub const fn default_timeout(mut self, timeout: Duration) -> Self {
+        self.default_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum number of observers [`Subject::try_attach`] accepts.
+    pub const fn max_observers(mut self, limit: usize) -> Self {
+        self.max_observers = Some(limit);
+        self
+    }
+}
+
+impl<T: Clone> SubjectBuilder<T> {
+    /// Keeps the last `capacity` events in a history buffer replayed to new observers, as
+    /// with [`Subject::with_history`].
+    pub fn history(mut self, capacity: usize) -> Self {
+        self.history = Some((capacity, T::clone));
+        self
+    }
+}
+
+impl<T: Send + Sync + 'static> SubjectBuilder<T> {
+    /// Creates a new `Subject` with this configuration.
+    pub fn build(&self) -> Subject<T> {
+        Subject::with_options(SubjectOptions {
+            history: self
+                .history
+                .map(|(capacity, clone)| History::with_clone(capacity, clone)),
+            notify_mode: self.notify_mode,
+            #[cfg(feature = "tokio")]
+            default_timeout: self.default_timeout,
+            max_observers: self.max_observers,
+        })
+    }
+}
+
+// Implemented by hand because deriving would require `T: Clone`.
+impl<T> Clone for SubjectBuilder<T> {
+    fn clone(&self) -> Self {
+        Self {
+            history: self.history,
+            notify_mode: self.notify_mode,
+            #[cfg(feature = "tokio")]
+            default_timeout: self.default_timeout,
+            max_observers: self.max_observers,
+        }
+    }
+}
+
+impl<T> Default for SubjectBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
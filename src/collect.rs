@@ -0,0 +1,124 @@
+//! Collectors, a counterpart to observers that hand a value back for every event.
+
+use async_trait::async_trait;
+use futures::FutureExt;
+use parking_lot::Mutex;
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+#[cfg(feature = "logging")]
+use tracing::error;
+
+/// A counterpart to [`Observer`](crate::Observer) that produces a value for every event.
+///
+/// Where observers are pure side effects, a collector returns a result, e.g. a validation
+/// score, which [`Collectors::notify_and_collect`] gathers from every registered collector.
+#[async_trait]
+pub trait Collector<T, R>: Send + Sync {
+    /// Called by [`Collectors::notify_and_collect`] with each event, returning this
+    /// collector's output for it.
+    async fn collect(&self, data: &T) -> R;
+}
+
+/// The registered collectors, by ID.
+type CollectorList<T, R> = Mutex<Vec<(u64, Arc<dyn Collector<T, R>>)>>;
+
+/// The state shared between all clones of a `Collectors` registry.
+struct CollectorsInner<T, R> {
+    collectors: CollectorList<T, R>,
+    next_id: Mutex<u64>,
+}
+
+/// A registry of [`Collector`]s, the `Subject` counterpart for collectors.
+///
+/// Collectors are identified by the ID returned from [`Collectors::attach`]. The registry
+/// is cheap to clone, and clones share the same set of collectors.
+pub struct Collectors<T, R> {
+    inner: Arc<CollectorsInner<T, R>>,
+}
+
+impl<T: Send + Sync + 'static, R: Send + 'static> Collectors<T, R> {
+    /// Creates a new registry without any collectors.
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(CollectorsInner {
+                collectors: Mutex::new(Vec::new()),
+                next_id: Mutex::new(0),
+            }),
+        }
+    }
+
+    /// Registers a `Collector`, returning its ID.
+    pub fn attach(&self, collector: Arc<dyn Collector<T, R>>) -> u64 {
+        let id = {
+            let mut next_id = self.inner.next_id.lock();
+            let id = *next_id;
+            *next_id += 1;
+            id
+        };
+        self.inner.collectors.lock().push((id, collector));
+        id
+    }
+
+    /// Removes the collector with the given ID, returning whether it was registered.
+    pub fn detach(&self, id: u64) -> bool {
+        let mut collectors = self.inner.collectors.lock();
+        let before = collectors.len();
+        collectors.retain(|(collector_id, _)| *collector_id != id);
+        collectors.len() != before
+    }
+
+    /// Returns the number of registered collectors.
+    pub fn len(&self) -> usize {
+        self.inner.collectors.lock().len()
+    }
+
+    /// Returns `true` if no collectors are registered.
+    pub fn is_empty(&self) -> bool {
+        self.inner.collectors.lock().is_empty()
+    }
+
+    /// Runs every registered collector concurrently on `data` and returns their outputs,
+    /// paired with the collector IDs, in registration order.
+    ///
+    /// As with `Subject::notify`, the collectors are snapshotted first, so the lock is not
+    /// held while they run. A collector that panics has no output to report; it is logged
+    /// and left out of the result.
+    pub async fn notify_and_collect(&self, data: &T) -> Vec<(u64, R)> {
+        let snapshot = self.inner.collectors.lock().clone();
+        let outputs = futures::future::join_all(snapshot.iter().map(|(id, collector)| {
+            AssertUnwindSafe(collector.collect(data))
+                .catch_unwind()
+                .map(move |output| (*id, output))
+        }))
+        .await;
+
+        outputs
+            .into_iter()
+            .filter_map(|(id, output)| match output {
+                Ok(output) => Some((id, output)),
+                Err(_) => {
+                    #[cfg(feature = "logging")]
+                    error!("Collector {} panicked during collect.", id);
+                    #[cfg(not(feature = "logging"))]
+                    let _ = id;
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+// Implemented by hand because deriving would require `T: Clone` and `R: Clone`.
+impl<T, R> Clone for Collectors<T, R> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static, R: Send + 'static> Default for Collectors<T, R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
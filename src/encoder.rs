@@ -0,0 +1,97 @@
+//! Pluggable encodings for observers that serialize events.
+//!
+//! Observers that write or send serialized data accept an `Arc<dyn Encoder<T>>`, so the
+//! wire format can be swapped without changing the observer itself. JSON encoders are
+//! available behind the `json` feature, MessagePack behind `msgpack` and CBOR behind `cbor`.
+
+#[cfg(any(feature = "json", feature = "msgpack", feature = "cbor"))]
+use serde::Serialize;
+use std::error::Error;
+use std::fmt;
+
+/// The error returned when an `Encoder` fails to encode an event.
+#[derive(Debug)]
+pub struct EncodeError {
+    source: Box<dyn Error + Send + Sync>,
+}
+
+impl EncodeError {
+    /// Creates a new `EncodeError` wrapping the underlying serializer error.
+    pub fn new(source: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        Self {
+            source: source.into(),
+        }
+    }
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to encode event: {}", self.source)
+    }
+}
+
+impl Error for EncodeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&*self.source)
+    }
+}
+
+/// The `Encoder` trait converts events into bytes for serializing observers.
+pub trait Encoder<T>: Send + Sync {
+    /// Encodes `data` into its byte representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `EncodeError` if `data` cannot be represented in this encoding.
+    fn encode(&self, data: &T) -> Result<Vec<u8>, EncodeError>;
+}
+
+/// Encodes events as compact, single-line JSON.
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonEncoder;
+
+#[cfg(feature = "json")]
+impl<T: Serialize> Encoder<T> for JsonEncoder {
+    fn encode(&self, data: &T) -> Result<Vec<u8>, EncodeError> {
+        serde_json::to_vec(data).map_err(EncodeError::new)
+    }
+}
+
+/// Encodes events as indented, multi-line JSON.
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PrettyJsonEncoder;
+
+#[cfg(feature = "json")]
+impl<T: Serialize> Encoder<T> for PrettyJsonEncoder {
+    fn encode(&self, data: &T) -> Result<Vec<u8>, EncodeError> {
+        serde_json::to_vec_pretty(data).map_err(EncodeError::new)
+    }
+}
+
+/// Encodes events as MessagePack, keeping struct field names in the output.
+#[cfg(feature = "msgpack")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MessagePackEncoder;
+
+#[cfg(feature = "msgpack")]
+impl<T: Serialize> Encoder<T> for MessagePackEncoder {
+    fn encode(&self, data: &T) -> Result<Vec<u8>, EncodeError> {
+        rmp_serde::to_vec_named(data).map_err(EncodeError::new)
+    }
+}
+
+/// Encodes events as CBOR.
+#[cfg(feature = "cbor")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CborEncoder;
+
+#[cfg(feature = "cbor")]
+impl<T: Serialize> Encoder<T> for CborEncoder {
+    fn encode(&self, data: &T) -> Result<Vec<u8>, EncodeError> {
+        let mut buffer = Vec::new();
+        ciborium::into_writer(data, &mut buffer).map_err(EncodeError::new)?;
+        Ok(buffer)
+    }
+}
@@ -0,0 +1,89 @@
+//! The errors returned when an observer fails to handle an event or can't be attached.
+
+use crate::encoder::EncodeError;
+use std::error::Error;
+use std::{fmt, io};
+
+/// The error returned by [`Observer::update`](crate::Observer::update) when an observer
+/// fails to handle an event.
+#[derive(Debug)]
+pub enum ObserverError {
+    /// The event could not be serialized.
+    Serialization(EncodeError),
+    /// An IO operation, such as writing to a stream or socket, failed.
+    Io(io::Error),
+    /// The channel the observer forwards events into has been closed.
+    ChannelClosed,
+    /// The observer panicked while handling the event. Only reported by the `Subject` and to
+    /// the error handlers of decorators delivering from a background task.
+    Panicked,
+    /// The observer exceeded the `Subject`'s update timeout. Only reported by the `Subject`.
+    TimedOut,
+    /// Any other failure, such as an error reported by a client library.
+    Other(Box<dyn Error + Send + Sync>),
+}
+
+impl ObserverError {
+    /// Creates an `ObserverError::Other` wrapping `error`.
+    pub fn other(error: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        Self::Other(error.into())
+    }
+}
+
+impl fmt::Display for ObserverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Serialization(error) => error.fmt(f),
+            Self::Io(error) => write!(f, "observer IO failed: {error}"),
+            Self::ChannelClosed => f.write_str("observer channel closed"),
+            Self::Panicked => f.write_str("observer panicked"),
+            Self::TimedOut => f.write_str("observer timed out"),
+            Self::Other(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for ObserverError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Serialization(error) => Some(error),
+            Self::Io(error) => Some(error),
+            Self::ChannelClosed | Self::Panicked | Self::TimedOut => None,
+            Self::Other(error) => Some(&**error),
+        }
+    }
+}
+
+impl From<EncodeError> for ObserverError {
+    fn from(error: EncodeError) -> Self {
+        Self::Serialization(error)
+    }
+}
+
+impl From<io::Error> for ObserverError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// The error returned by [`Subject::try_attach`](crate::Subject::try_attach) and
+/// [`Subject::attach_with_id`](crate::Subject::attach_with_id) when an observer could not be
+/// attached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttachError {
+    /// The `Subject` already holds its configured maximum number of observers.
+    LimitReached,
+    /// An observer with the requested ID is already attached.
+    IdInUse,
+}
+
+impl fmt::Display for AttachError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LimitReached => f.write_str("observer limit reached"),
+            Self::IdInUse => f.write_str("observer ID already in use"),
+        }
+    }
+}
+
+impl Error for AttachError {}
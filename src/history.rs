@@ -0,0 +1,48 @@
+//! The bounded event history kept by a `Subject` for replay on attach.
+
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+
+/// A ring buffer holding clones of the most recent events notified on a `Subject`.
+pub(crate) struct History<T> {
+    capacity: usize,
+    events: Mutex<VecDeque<T>>,
+    // `notify` doesn't require `T: Clone`, so the clone function is captured when the
+    // history is enabled and the bound is known to hold.
+    clone: fn(&T) -> T,
+}
+
+impl<T: Clone> History<T> {
+    /// Creates an empty history retaining at most `capacity` events.
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self::with_clone(capacity, T::clone)
+    }
+}
+
+impl<T> History<T> {
+    /// Creates an empty history retaining at most `capacity` events, copied with `clone`.
+    pub(crate) fn with_clone(capacity: usize, clone: fn(&T) -> T) -> Self {
+        Self {
+            capacity,
+            events: Mutex::new(VecDeque::with_capacity(capacity)),
+            clone,
+        }
+    }
+
+    /// Appends a clone of `data`, evicting the oldest event when the buffer is full.
+    pub(crate) fn record(&self, data: &T) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut events = self.events.lock();
+        if events.len() == self.capacity {
+            events.pop_front();
+        }
+        events.push_back((self.clone)(data));
+    }
+
+    /// Returns clones of the buffered events, oldest first.
+    pub(crate) fn snapshot(&self) -> Vec<T> {
+        self.events.lock().iter().map(self.clone).collect()
+    }
+}
@@ -1,8 +1,47 @@
 use async_trait::async_trait;
-use parking_lot::Mutex;
+use futures::FutureExt;
+use futures::channel::oneshot;
+use history::History;
+#[cfg(feature = "metrics")]
+use metrics::SubjectMetrics;
+use parking_lot::{Mutex, MutexGuard};
+use std::any::Any;
+use std::collections::HashMap;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
+#[cfg(feature = "tokio")]
+use tokio_util::sync::CancellationToken;
 #[cfg(feature = "logging")]
-use tracing::{debug, info, trace};
+use tracing::{Instrument, debug, error, info, info_span, trace};
+
+mod builder;
+mod collect;
+pub mod encoder;
+mod error;
+mod history;
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "native-async")]
+mod native;
+pub mod observers;
+mod scope;
+mod stream;
+#[cfg(feature = "testing")]
+pub mod testing;
+
+pub use builder::SubjectBuilder;
+pub use collect::{Collector, Collectors};
+pub use error::{AttachError, ObserverError};
+#[cfg(feature = "metrics")]
+pub use metrics::SubjectMetricsSnapshot;
+#[cfg(feature = "native-async")]
+pub use native::{NativeAdapter, NativeObserver};
+pub use scope::Scope;
+#[cfg(feature = "tokio")]
+pub use stream::StreamDriver;
+pub use stream::SubjectStream;
 
 /// The `Observer` trait defines the contract for any type that wants to be notified of events.
 ///
@@ -12,17 +51,386 @@ pub trait Observer<T>: Send + Sync {
     /// The update method is called by the `Subject` when a new event occurs.
     ///
     /// It receives a reference to the data and performs its logic.
-    async fn update(&self, data: &T);
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ObserverError` if the observer failed to handle the event. The `Subject`
+    /// reports the failure and carries on notifying the other observers.
+    async fn update(&self, data: &T) -> Result<(), ObserverError>;
+
+    /// Called by the `Subject` when the observer is attached, before it receives any event.
+    ///
+    /// Use this for setup such as opening a connection or registering a metric.
+    /// The default implementation does nothing.
+    async fn on_attach(&self) {}
+
+    /// Called by the `Subject` once the observer has been detached.
+    ///
+    /// Explicit detachment through [`Subject::detach`] awaits this hook. When the observer is
+    /// detached because its `ObserverHandle` was dropped, the hook can't be awaited inside
+    /// `Drop`: with the `tokio` feature it is spawned onto the current Tokio runtime, and
+    /// otherwise it is skipped. The default implementation does nothing.
+    async fn on_detach(&self) {}
+
+    /// Called by [`Subject::notify_shared`] with a shared reference to the event.
+    ///
+    /// Observers that need to keep or forward the event can override this to clone the
+    /// cheap `Arc` instead of deep-cloning the data. The default implementation calls
+    /// `update`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ObserverError` if the observer failed to handle the event.
+    async fn update_shared(&self, data: &Arc<T>) -> Result<(), ObserverError>
+    where
+        T: Send + Sync,
+    {
+        self.update(data).await
+    }
+
+    /// Called by [`Subject::notify_batch`] with a whole batch of events.
+    ///
+    /// Observers that can handle many events at once more cheaply, e.g. by sending them in
+    /// one request or writing them under one lock, can override this. The default
+    /// implementation calls `update` for each event in order and stops at the first error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ObserverError` if the observer failed to handle the batch.
+    async fn update_batch(&self, data: &[T]) -> Result<(), ObserverError>
+    where
+        T: Send + Sync,
+    {
+        for event in data {
+            self.update(event).await?;
+        }
+        Ok(())
+    }
+}
+
+/// A synchronous counterpart to [`Observer`] for observers that never need to await.
+///
+/// Incrementing a counter or pushing into an in-memory buffer doesn't need an `async fn`;
+/// implement this trait instead and attach the observer with [`Subject::attach_sync`], or
+/// wrap it in a [`SyncAdapter`] wherever an `Observer` is expected.
+pub trait SyncObserver<T>: Send + Sync {
+    /// Called by the `Subject` when a new event occurs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ObserverError` if the observer failed to handle the event.
+    fn update(&self, data: &T) -> Result<(), ObserverError>;
+}
+
+/// Adapts a [`SyncObserver`] into an [`Observer`].
+///
+/// The adapter's `update` calls the synchronous method directly, without any await point.
+/// A blanket `Observer` implementation for every `SyncObserver` would conflict with the
+/// crate's generic observers, hence the wrapper.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SyncAdapter<O>(pub O);
+
+#[async_trait]
+impl<T: Sync, O: SyncObserver<T>> Observer<T> for SyncAdapter<O> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        self.0.update(data)
+    }
+}
+
+/// An event on its way to an observer, either borrowed or shared through an `Arc`, or a
+/// batch of events.
+enum Payload<'a, T> {
+    Borrowed(&'a T),
+    Shared(&'a Arc<T>),
+    Batch(&'a [T]),
+}
+
+impl<'a, T> Payload<'a, T> {
+    /// Returns the events carried by the payload.
+    fn events(self) -> &'a [T] {
+        match self {
+            Self::Borrowed(data) => std::slice::from_ref(data),
+            Self::Shared(data) => std::slice::from_ref(&**data),
+            Self::Batch(data) => data,
+        }
+    }
+}
+
+// Implemented by hand because deriving would require `T: Copy`.
+impl<T> Clone for Payload<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Payload<'_, T> {}
+
+/// How the `Subject` holds on to an attached observer.
+enum ObserverRef<T> {
+    Strong(Arc<dyn Observer<T>>),
+    Weak(Weak<dyn Observer<T>>),
+}
+
+impl<T> ObserverRef<T> {
+    /// Returns a strong reference to the observer, or `None` if a weakly-held observer
+    /// has been dropped.
+    fn upgrade(&self) -> Option<Arc<dyn Observer<T>>> {
+        match self {
+            Self::Strong(observer) => Some(Arc::clone(observer)),
+            Self::Weak(observer) => observer.upgrade(),
+        }
+    }
+}
+
+/// An attached observer together with its registration metadata.
+struct ObserverEntry<T> {
+    id: u64,
+    // Tells registrations apart that reused an ID, so a stale handle can't remove a newer one.
+    generation: u64,
+    observer: ObserverRef<T>,
+    tags: Vec<String>,
+    priority: i32,
+    paused: bool,
+}
+
+/// A freshly registered observer: its ID and generation, and the history to replay to it.
+struct Registration<T> {
+    id: u64,
+    generation: u64,
+    replay: Option<Vec<T>>,
 }
 
 /// A type alias for the internal list of observers, to improve readability.
-type ObserverList<T> = Mutex<Vec<(u64, Arc<dyn Observer<T>>)>>;
+type ObserverList<T> = Mutex<Vec<ObserverEntry<T>>>;
+
+/// Observers of equal priority, which are notified together.
+type Tier<T> = Vec<(u64, Arc<dyn Observer<T>>)>;
+
+/// An observer's payload size cap in bytes and the function measuring an event, see
+/// `Subject::attach_with_limits`.
+type PayloadCap<T> = (usize, fn(&T) -> usize);
+
+/// The handler receiving events an observer failed to handle, see [`Subject::on_dead_letter`].
+type DeadLetterHandler<T> = Arc<dyn Fn(&T, u64, &ObserverError) + Send + Sync>;
+
+/// The handler receiving every failed update, see [`Subject::on_error`].
+type ErrorHandler = Arc<dyn Fn(ObserverErrorContext<'_>) + Send + Sync>;
+
+/// A failed update, reported to the handler set with [`Subject::on_error`].
+#[derive(Debug, Clone, Copy)]
+pub struct ObserverErrorContext<'a> {
+    /// The ID of the observer whose update failed.
+    pub observer_id: u64,
+    /// What went wrong. Panics and timeouts are reported as `ObserverError::Panicked` and
+    /// `ObserverError::TimedOut`.
+    pub error: &'a ObserverError,
+}
+
+/// The handler receiving attach and detach events, see [`Subject::on_lifecycle`].
+type LifecycleHandler = Arc<dyn Fn(ObserverLifecycleEvent) + Send + Sync>;
+
+/// Whether an observer joined or left a `Subject`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifecycleKind {
+    /// The observer was attached.
+    Attached,
+    /// The observer was removed, by any means.
+    Detached,
+}
+
+/// A change to a `Subject`'s observer set, reported to the handler set with
+/// [`Subject::on_lifecycle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObserverLifecycleEvent {
+    /// The ID of the observer that was attached or detached.
+    pub id: u64,
+    /// Whether the observer was attached or detached.
+    pub kind: LifecycleKind,
+}
 
 // A private struct that holds the internal state of the Subject.
 // This allows us to use a `Weak` reference to it from the handle.
 struct SubjectInner<T> {
     observers: ObserverList<T>,
     next_observer_id: Mutex<u64>,
+    next_generation: AtomicU64,
+    history: Option<History<T>>,
+    notify_mode: NotifyMode,
+    #[cfg(feature = "tokio")]
+    default_timeout: Option<Duration>,
+    max_observers: Option<usize>,
+    // Maps the keys of observers attached through `attach_unique` to their IDs.
+    unique_keys: Mutex<HashMap<String, u64>>,
+    // The moving average of each observer's update duration, `None` until its first update.
+    latencies: Mutex<HashMap<u64, Option<Duration>>>,
+    // The payload size caps of observers attached through `attach_with_limits`, with the
+    // function measuring an event. Always locked after `observers` when both are held.
+    payload_caps: Mutex<HashMap<u64, PayloadCap<T>>>,
+    // The tasks waiting in `wait_for_detach`, by the ID of the observer they wait for.
+    detach_waiters: Mutex<HashMap<u64, Vec<oneshot::Sender<()>>>>,
+    // The number of notifications currently being delivered, and the tasks waiting for it
+    // to drop to zero.
+    in_flight: AtomicUsize,
+    idle_waiters: Mutex<Vec<oneshot::Sender<()>>>,
+    shutting_down: AtomicBool,
+    enabled: AtomicBool,
+    dead_letter: Mutex<Option<DeadLetterHandler<T>>>,
+    lifecycle: Mutex<Option<LifecycleHandler>>,
+    on_error: Mutex<Option<ErrorHandler>>,
+    // Handles on the subjects this one is derived from, detached when it is dropped.
+    upstream: Mutex<Vec<Box<dyn Send + Sync>>>,
+    #[cfg(feature = "metrics")]
+    metrics: SubjectMetrics,
+    // `ObserverHandle`'s `Drop` impl can't require `T: Send + Sync + 'static`, which running
+    // the `on_detach` hook needs, so the bounded code is reached through this pointer.
+    detach_dropped: fn(&SubjectInner<T>, u64, u64) -> bool,
+}
+
+impl<T> SubjectInner<T> {
+    /// Removes the observer with the given ID, returning it if it was attached. With a
+    /// `generation`, the observer is only removed if it is still that registration, so a
+    /// handle outliving its observer can't remove another one attached under the same ID.
+    fn remove(&self, id: u64, generation: Option<u64>) -> Option<ObserverRef<T>> {
+        let removed = self.remove_locked(&mut self.observers.lock(), id, generation)?;
+        self.emit_lifecycle(id, LifecycleKind::Detached);
+        Some(removed)
+    }
+
+    /// Does the work of [`SubjectInner::remove`] on the locked observer list. The caller
+    /// reports the removal to the lifecycle handler once the list is unlocked.
+    fn remove_locked(
+        &self,
+        observers: &mut Vec<ObserverEntry<T>>,
+        id: u64,
+        generation: Option<u64>,
+    ) -> Option<ObserverRef<T>> {
+        let index = observers.iter().position(|entry| {
+            entry.id == id && generation.is_none_or(|generation| entry.generation == generation)
+        })?;
+        self.forget(id);
+        #[cfg(feature = "metrics")]
+        self.metrics.record_detach(1);
+        Some(observers.remove(index).observer)
+    }
+
+    /// Drops the state kept about a removed observer: the unique key pointing at it, if
+    /// any, its latency average and the tasks waiting for it to be detached, which are
+    /// woken. The lifecycle handler is not told here, since this may run with the observer
+    /// list locked and the handler may call back into the `Subject`.
+    fn forget(&self, id: u64) {
+        self.unique_keys.lock().retain(|_, key_id| *key_id != id);
+        self.latencies.lock().remove(&id);
+        self.payload_caps.lock().remove(&id);
+        for waiter in self.detach_waiters.lock().remove(&id).into_iter().flatten() {
+            let _ = waiter.send(());
+        }
+    }
+
+    /// Folds the duration of an update into the observer's latency average. Observers that
+    /// have been removed in the meantime are ignored.
+    fn record_latency(&self, id: u64, elapsed: Duration) {
+        // Each update weighs a fifth, so the average follows changes within a few updates.
+        const WEIGHT: f64 = 0.2;
+        if let Some(average) = self.latencies.lock().get_mut(&id) {
+            *average = Some(match *average {
+                Some(average) => Duration::from_secs_f64(
+                    average.as_secs_f64()
+                        + (elapsed.as_secs_f64() - average.as_secs_f64()) * WEIGHT,
+                ),
+                None => elapsed,
+            });
+        }
+    }
+
+    /// Reports an attach or detach to the lifecycle handler, if one is set.
+    fn emit_lifecycle(&self, id: u64, kind: LifecycleKind) {
+        let handler = self.lifecycle.lock().clone();
+        if let Some(handler) = handler {
+            handler(ObserverLifecycleEvent { id, kind });
+        }
+    }
+
+    /// Measures a payload against the observers' size caps, returning `None` without
+    /// measuring while no observer has a cap. Called once per notification, before the
+    /// observers lock is taken.
+    fn payload_size(&self, data: Payload<'_, T>) -> Option<usize> {
+        let size_of = self
+            .payload_caps
+            .lock()
+            .values()
+            .next()
+            .map(|&(_, size_of)| size_of)?;
+        Some(data.events().iter().fold(0, |total: usize, event| {
+            total.saturating_add(size_of(event))
+        }))
+    }
+
+    /// Returns whether a payload of the given size exceeds the observer's size cap.
+    fn exceeds_cap(&self, id: u64, size: Option<usize>) -> bool {
+        size.is_some_and(|size| {
+            self.payload_caps
+                .lock()
+                .get(&id)
+                .is_some_and(|&(cap, _)| size > cap)
+        })
+    }
+
+    /// Registers a notification as in flight, or returns `None` if the `Subject` is disabled
+    /// or shutting down and no longer accepts notifications.
+    fn begin_notification(&self) -> Option<InFlight<'_, T>> {
+        if !self.enabled.load(Ordering::Relaxed) {
+            return None;
+        }
+        // Incrementing before checking the flag guarantees that `shutdown` either sees this
+        // notification as in flight or this notification sees the flag.
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        let guard = InFlight { inner: self };
+        if self.shutting_down.load(Ordering::SeqCst) {
+            #[cfg(feature = "logging")]
+            debug!("Subject is shutting down, notification rejected.");
+            return None;
+        }
+        Some(guard)
+    }
+}
+
+/// Marks a notification as in flight for as long as it is alive.
+struct InFlight<'a, T> {
+    inner: &'a SubjectInner<T>,
+}
+
+impl<T> Drop for InFlight<'_, T> {
+    fn drop(&mut self) {
+        if self.inner.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+            for waiter in self.inner.idle_waiters.lock().drain(..) {
+                let _ = waiter.send(());
+            }
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> SubjectInner<T> {
+    /// Detaches the observer whose handle was dropped or detached, returning whether it
+    /// was still attached, and runs its `on_detach` hook if a runtime is available to run
+    /// it on.
+    fn detach_dropped(&self, id: u64, generation: u64) -> bool {
+        let Some(observer) = self.remove(id, Some(generation)) else {
+            return false;
+        };
+        #[cfg(feature = "logging")]
+        info!("Observer with ID {} detached by its handle.", id);
+        let Some(observer) = observer.upgrade() else {
+            return true;
+        };
+
+        #[cfg(feature = "tokio")]
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            runtime.spawn(async move { observer.on_detach().await });
+        }
+        #[cfg(not(feature = "tokio"))]
+        drop(observer);
+        true
+    }
 }
 
 /// A handle for an `Observer`, used to uniquely identify and detach it from the `Subject`.
@@ -32,25 +440,192 @@ struct SubjectInner<T> {
 #[derive(Debug)]
 pub struct ObserverHandle<T> {
     id: u64,
+    generation: u64,
     subject_weak: Weak<SubjectInner<T>>,
 }
 
+impl<T> ObserverHandle<T> {
+    /// Returns the ID of the observer this handle refers to.
+    pub const fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Detaches the observer right away, returning whether it was still attached.
+    ///
+    /// This does what dropping the handle would, but keeps the handle around, e.g. as a
+    /// struct field; its eventual `Drop` then does nothing. Calling `detach_now` again
+    /// returns `false`.
+    pub fn detach_now(&mut self) -> bool {
+        let subject_weak = std::mem::take(&mut self.subject_weak);
+        subject_weak.upgrade().is_some_and(|subject_arc| {
+            (subject_arc.detach_dropped)(&subject_arc, self.id, self.generation)
+        })
+    }
+}
+
 impl<T> Drop for ObserverHandle<T> {
     fn drop(&mut self) {
         if let Some(subject_arc) = self.subject_weak.upgrade() {
-            let mut observers = subject_arc.observers.lock();
-            if let Some(index) = observers.iter().position(|(id, _)| *id == self.id) {
-                observers.remove(index);
-                #[cfg(feature = "logging")]
-                info!(
-                    "Observer with ID {} automatically detached by drop.",
-                    self.id
-                );
-            }
+            (subject_arc.detach_dropped)(&subject_arc, self.id, self.generation);
+        }
+    }
+}
+
+/// A set of `ObserverHandle`s that are detached together.
+///
+/// Dropping the group drops every handle in it, automatically detaching all of its
+/// observers, so a set of related observers can be torn down in one go.
+#[derive(Debug)]
+pub struct GroupHandle<T> {
+    handles: Vec<ObserverHandle<T>>,
+}
+
+impl<T> GroupHandle<T> {
+    /// Returns the IDs of the observers in this group, in attach order.
+    pub fn ids(&self) -> Vec<u64> {
+        self.handles.iter().map(ObserverHandle::id).collect()
+    }
+
+    /// Returns the number of observers in this group.
+    pub const fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    /// Returns `true` if the group holds no observers.
+    pub const fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+
+    /// Splits the group back into its individual handles.
+    pub fn into_handles(self) -> Vec<ObserverHandle<T>> {
+        self.handles
+    }
+}
+
+impl<T> From<Vec<ObserverHandle<T>>> for GroupHandle<T> {
+    fn from(handles: Vec<ObserverHandle<T>>) -> Self {
+        Self { handles }
+    }
+}
+
+/// How a `Subject` runs its observers when an event is notified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NotifyMode {
+    /// All observers run concurrently, so a slow observer doesn't delay the others.
+    #[default]
+    Concurrent,
+    /// Observers run one after another, highest priority first and otherwise in the order
+    /// they were attached.
+    Sequential,
+}
+
+/// The configuration a `Subject` is created with.
+struct SubjectOptions<T> {
+    history: Option<History<T>>,
+    notify_mode: NotifyMode,
+    #[cfg(feature = "tokio")]
+    default_timeout: Option<Duration>,
+    max_observers: Option<usize>,
+}
+
+impl<T> Default for SubjectOptions<T> {
+    fn default() -> Self {
+        Self {
+            history: None,
+            notify_mode: NotifyMode::default(),
+            #[cfg(feature = "tokio")]
+            default_timeout: None,
+            max_observers: None,
         }
     }
 }
 
+/// The outcome of [`Subject::notify_cancellable`] and [`Subject::notify_by_deadline`],
+/// listing observers by ID.
+#[cfg(feature = "tokio")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NotifyReport {
+    /// Observers whose `update` ran to completion.
+    pub completed: Vec<u64>,
+    /// Observers whose `update` was abandoned because the token was cancelled or the
+    /// deadline passed.
+    pub cancelled: Vec<u64>,
+}
+
+/// The outcome of [`Subject::notify_acked`], holding each notified observer's result.
+///
+/// The common checks are one call away, while iterating over the report yields every
+/// observer's ID and result.
+#[derive(Debug, Default)]
+pub struct AckReport {
+    /// The ID and result of each notified observer, in the order they were notified. A
+    /// panic or a timeout is reported as `ObserverError::Panicked` or
+    /// `ObserverError::TimedOut`.
+    pub results: Vec<(u64, Result<(), ObserverError>)>,
+}
+
+impl AckReport {
+    /// Returns the result of the observer with the given ID, or `None` if it wasn't notified.
+    pub fn result(&self, id: u64) -> Option<&Result<(), ObserverError>> {
+        self.results
+            .iter()
+            .find(|(result_id, _)| *result_id == id)
+            .map(|(_, result)| result)
+    }
+
+    /// Returns `true` if every notified observer handled the event.
+    pub fn all_succeeded(&self) -> bool {
+        self.results.iter().all(|(_, result)| result.is_ok())
+    }
+
+    /// Returns `true` if any notified observer failed to handle the event.
+    pub fn any_failed(&self) -> bool {
+        !self.all_succeeded()
+    }
+
+    /// Returns the error of the first observer, in notification order, that failed.
+    pub fn first_error(&self) -> Option<&ObserverError> {
+        self.results
+            .iter()
+            .find_map(|(_, result)| result.as_ref().err())
+    }
+
+    /// Returns the number of observers that handled the event.
+    pub fn succeeded(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|(_, result)| result.is_ok())
+            .count()
+    }
+
+    /// Returns the IDs of the observers that failed to handle the event.
+    pub fn failed_ids(&self) -> Vec<u64> {
+        self.results
+            .iter()
+            .filter(|(_, result)| result.is_err())
+            .map(|(id, _)| *id)
+            .collect()
+    }
+}
+
+impl IntoIterator for AckReport {
+    type Item = (u64, Result<(), ObserverError>);
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a AckReport {
+    type Item = &'a (u64, Result<(), ObserverError>);
+    type IntoIter = std::slice::Iter<'a, (u64, Result<(), ObserverError>)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.iter()
+    }
+}
+
 /// The `Subject` struct manages the list of observers and notifies them of events.
 ///
 /// It is thread-safe and can be cloned to be used across multiple threads or async tasks.
@@ -62,43 +637,407 @@ pub struct Subject<T> {
 impl<T: Send + Sync + 'static> Subject<T> {
     /// Creates a new `Subject` with an empty list of observers.
     pub fn new() -> Self {
+        Self::with_options(SubjectOptions::default())
+    }
+
+    /// Creates a new `Subject` holding at most `limit` observers, see [`Subject::try_attach`].
+    pub fn with_max_observers(limit: usize) -> Self {
+        Self::with_options(SubjectOptions {
+            max_observers: Some(limit),
+            ..SubjectOptions::default()
+        })
+    }
+
+    /// Returns a [`SubjectBuilder`] for creating a configured `Subject`.
+    pub const fn builder() -> SubjectBuilder<T> {
+        SubjectBuilder::new()
+    }
+
+    fn with_options(options: SubjectOptions<T>) -> Self {
         Self {
             inner: Arc::new(SubjectInner {
                 observers: Mutex::new(Vec::new()),
                 next_observer_id: Mutex::new(0),
+                next_generation: AtomicU64::new(0),
+                history: options.history,
+                notify_mode: options.notify_mode,
+                #[cfg(feature = "tokio")]
+                default_timeout: options.default_timeout,
+                max_observers: options.max_observers,
+                unique_keys: Mutex::new(HashMap::new()),
+                latencies: Mutex::new(HashMap::new()),
+                payload_caps: Mutex::new(HashMap::new()),
+                detach_waiters: Mutex::new(HashMap::new()),
+                in_flight: AtomicUsize::new(0),
+                idle_waiters: Mutex::new(Vec::new()),
+                shutting_down: AtomicBool::new(false),
+                enabled: AtomicBool::new(true),
+                dead_letter: Mutex::new(None),
+                lifecycle: Mutex::new(None),
+                on_error: Mutex::new(None),
+                upstream: Mutex::new(Vec::new()),
+                #[cfg(feature = "metrics")]
+                metrics: SubjectMetrics::default(),
+                detach_dropped: SubjectInner::detach_dropped,
             }),
         }
     }
 
+    /// Returns the events currently held in the history buffer, oldest first.
+    ///
+    /// The result is empty unless the `Subject` was created with [`Subject::with_history`].
+    pub fn history(&self) -> Vec<T> {
+        self.inner
+            .history
+            .as_ref()
+            .map(History::snapshot)
+            .unwrap_or_default()
+    }
+
     /// Attaches an `Observer` to the `Subject`.
     ///
-    /// The observer must be wrapped in `Arc` for shared ownership. Returns a unique handle
-    /// that will automatically detach the observer when dropped.
-    pub fn attach(&self, observer: Arc<dyn Observer<T>>) -> ObserverHandle<T> {
-        let mut observers = self.inner.observers.lock();
+    /// The observer must be wrapped in `Arc` for shared ownership. Its `on_attach` hook is
+    /// awaited before it becomes visible to `notify`. Returns a unique handle that will
+    /// automatically detach the observer when dropped.
+    ///
+    /// If the `Subject` keeps a history, the buffered events are replayed to the new
+    /// observer, oldest first, before this method returns.
+    pub async fn attach(&self, observer: Arc<dyn Observer<T>>) -> ObserverHandle<T> {
+        let stored = ObserverRef::Strong(Arc::clone(&observer));
+        self.attach_entry(observer, stored, Vec::new(), 0).await
+    }
+
+    /// Attaches an `Observer` unless the `Subject` is at its observer limit.
+    ///
+    /// The limit is set with [`Subject::with_max_observers`] or
+    /// [`SubjectBuilder::max_observers`] and guards against runaway registration, e.g. when
+    /// untrusted callers can attach observers. The check and the registration happen
+    /// atomically, so concurrent calls never exceed the limit. Plain [`Subject::attach`] and
+    /// the other attach methods ignore the limit, while their observers still count towards it.
+    ///
+    /// The observer's `on_attach` hook runs before the check; if the observer is rejected,
+    /// its `on_detach` hook is awaited before returning.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AttachError::LimitReached` if the `Subject` already holds its maximum number
+    /// of observers.
+    pub async fn try_attach(
+        &self,
+        observer: Arc<dyn Observer<T>>,
+    ) -> Result<ObserverHandle<T>, AttachError> {
+        observer.on_attach().await;
+
+        let registered = {
+            let mut observers = self.inner.observers.lock();
+            let full = self
+                .inner
+                .max_observers
+                .is_some_and(|limit| observers.len() >= limit);
+            (!full).then(|| {
+                let id = self.next_id(&observers);
+                let stored = ObserverRef::Strong(Arc::clone(&observer));
+                self.register(&mut observers, id, stored, Vec::new(), 0)
+            })
+        };
+        let Some(registration) = registered else {
+            #[cfg(feature = "logging")]
+            debug!("Observer limit reached, attach rejected.");
+            observer.on_detach().await;
+            return Err(AttachError::LimitReached);
+        };
+        Ok(self.finish_attach(&observer, registration).await)
+    }
+
+    /// Attaches an `Observer` under a caller-supplied ID instead of an auto-assigned one.
+    ///
+    /// IDs chosen by the caller can be stable across restarts or derived from e.g. a plugin
+    /// name. The returned handle detaches by that ID like any other. Auto-assigned IDs skip
+    /// IDs that are in use, but an ID taken by [`Subject::attach`] first makes this method
+    /// fail, so callers mixing both should keep their IDs in a range the counter won't
+    /// reach, e.g. the upper half of `u64`.
+    ///
+    /// The observer's `on_attach` hook runs before the check; if the observer is rejected,
+    /// its `on_detach` hook is awaited before returning.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AttachError::IdInUse` if an observer with the same ID is already attached.
+    pub async fn attach_with_id(
+        &self,
+        id: u64,
+        observer: Arc<dyn Observer<T>>,
+    ) -> Result<ObserverHandle<T>, AttachError> {
+        observer.on_attach().await;
+
+        let registered = {
+            let mut observers = self.inner.observers.lock();
+            let in_use = observers.iter().any(|entry| entry.id == id);
+            (!in_use).then(|| {
+                let stored = ObserverRef::Strong(Arc::clone(&observer));
+                self.register(&mut observers, id, stored, Vec::new(), 0)
+            })
+        };
+        let Some(registration) = registered else {
+            #[cfg(feature = "logging")]
+            debug!("Observer ID {} already in use, attach rejected.", id);
+            observer.on_detach().await;
+            return Err(AttachError::IdInUse);
+        };
+        Ok(self.finish_attach(&observer, registration).await)
+    }
+
+    /// Attaches an `Observer` with the given priority.
+    ///
+    /// Notifications run observers in tiers of equal priority, highest first: each tier is
+    /// awaited in full before the next one starts, while the observers within a tier run as
+    /// usual. This lets e.g. persisting an event finish before analytics are sent, without
+    /// serializing the whole fan-out. Plain [`Subject::attach`] uses priority 0.
+    pub async fn attach_with_priority(
+        &self,
+        observer: Arc<dyn Observer<T>>,
+        priority: i32,
+    ) -> ObserverHandle<T> {
+        let stored = ObserverRef::Strong(Arc::clone(&observer));
+        self.attach_entry(observer, stored, Vec::new(), priority)
+            .await
+    }
+
+    /// Attaches several observers, returning their handles in the same order.
+    ///
+    /// The observers are attached one after another, exactly as by repeated calls to
+    /// [`Subject::attach`]. Wrap the result in a [`GroupHandle`] to detach them together.
+    pub async fn attach_many(
+        &self,
+        observers: Vec<Arc<dyn Observer<T>>>,
+    ) -> Vec<ObserverHandle<T>> {
+        let mut handles = Vec::with_capacity(observers.len());
+        for observer in observers {
+            handles.push(self.attach(observer).await);
+        }
+        handles
+    }
+
+    /// Attaches a [`SyncObserver`], wrapping it in a [`SyncAdapter`].
+    ///
+    /// Otherwise this behaves exactly like [`Subject::attach`].
+    pub async fn attach_sync(&self, observer: impl SyncObserver<T> + 'static) -> ObserverHandle<T> {
+        self.attach(Arc::new(SyncAdapter(observer))).await
+    }
+
+    /// Attaches an `Observer` that the `Subject` only holds weakly.
+    ///
+    /// The `Subject` doesn't keep the observer alive: once the last strong reference held
+    /// elsewhere is dropped, notifications skip it and the dead entry is pruned the next time
+    /// the `Subject` notifies. This suits plugin systems where the observer's lifetime is
+    /// owned by someone else. Returns `None` if the observer has already been dropped.
+    pub async fn attach_weak(&self, observer: Weak<dyn Observer<T>>) -> Option<ObserverHandle<T>> {
+        let live = observer.upgrade()?;
+        Some(
+            self.attach_entry(live, ObserverRef::Weak(observer), Vec::new(), 0)
+                .await,
+        )
+    }
+
+    /// Attaches an `Observer` under a key, replacing any observer previously attached with
+    /// the same key.
+    ///
+    /// This keeps a reconnect loop that re-attaches the same logical observer from ending up
+    /// with duplicate deliveries. The previous observer is swapped for the new one in a
+    /// single step, so no notification reaches both. The replaced observer's `on_detach`
+    /// hook is awaited once the new one is attached; its old handle becomes a no-op. Returns
+    /// the new handle and whether a previous observer was replaced.
+    pub async fn attach_unique(
+        &self,
+        key: impl Into<String>,
+        observer: Arc<dyn Observer<T>>,
+    ) -> (ObserverHandle<T>, bool) {
+        observer.on_attach().await;
+        let key = key.into();
+        let (registration, replaced) = {
+            let mut observers = self.inner.observers.lock();
+            let previous = self.inner.unique_keys.lock().get(&key).copied();
+            let replaced = previous.and_then(|previous_id| {
+                let replaced = self
+                    .inner
+                    .remove_locked(&mut observers, previous_id, None)?;
+                Some((previous_id, replaced))
+            });
+            let id = self.next_id(&observers);
+            let stored = ObserverRef::Strong(Arc::clone(&observer));
+            let registration = self.register(&mut observers, id, stored, Vec::new(), 0);
+            self.inner.unique_keys.lock().insert(key, id);
+            (registration, replaced)
+        };
+        if let Some((previous_id, _)) = &replaced {
+            self.inner
+                .emit_lifecycle(*previous_id, LifecycleKind::Detached);
+        }
+        let handle = self.finish_attach(&observer, registration).await;
+        let Some((previous_id, replaced)) = replaced else {
+            return (handle, false);
+        };
+        #[cfg(feature = "logging")]
+        info!(
+            "Observer with ID {} replaced by observer with ID {}.",
+            previous_id, handle.id
+        );
+        #[cfg(not(feature = "logging"))]
+        let _ = previous_id;
+        if let Some(replaced) = replaced.upgrade() {
+            replaced.on_detach().await;
+        }
+        (handle, true)
+    }
+
+    /// Attaches an `Observer` that carries the given tags.
+    ///
+    /// Tagged observers take part in regular notifications and can additionally be
+    /// targeted as a group through [`Subject::notify_group`].
+    pub async fn attach_tagged(
+        &self,
+        observer: Arc<dyn Observer<T>>,
+        tags: &[&str],
+    ) -> ObserverHandle<T> {
+        let tags = tags.iter().map(|tag| (*tag).to_owned()).collect();
+        let stored = ObserverRef::Strong(Arc::clone(&observer));
+        self.attach_entry(observer, stored, tags, 0).await
+    }
+
+    /// Attaches every observer currently registered on this `Subject` to `other` as well,
+    /// returning the new handles in attach order.
+    ///
+    /// The observers are shared, not copied: the same instances are registered on both
+    /// subjects, so an event notified on either one reaches them. This allows swapping in a
+    /// replacement `Subject` during a reconfiguration without losing subscribers. Tags,
+    /// priorities and weak registrations are carried over, while paused observers are
+    /// attached active. Each observer's `on_attach` hook runs again, and `other`'s history
+    /// is replayed to it.
+    pub async fn clone_observers_to(&self, other: &Self) -> Vec<ObserverHandle<T>> {
+        let entries: Vec<_> = self
+            .inner
+            .observers
+            .lock()
+            .iter()
+            .filter_map(|entry| {
+                let live = entry.observer.upgrade()?;
+                let stored = match &entry.observer {
+                    ObserverRef::Strong(_) => ObserverRef::Strong(Arc::clone(&live)),
+                    ObserverRef::Weak(observer) => ObserverRef::Weak(Weak::clone(observer)),
+                };
+                Some((live, stored, entry.tags.clone(), entry.priority))
+            })
+            .collect();
+
+        let mut handles = Vec::with_capacity(entries.len());
+        for (observer, stored, tags, priority) in entries {
+            handles.push(other.attach_entry(observer, stored, tags, priority).await);
+        }
+        handles
+    }
+
+    /// Registers `stored`, running the attach hook and history replay on `observer`, a live
+    /// reference to the same observer.
+    async fn attach_entry(
+        &self,
+        observer: Arc<dyn Observer<T>>,
+        stored: ObserverRef<T>,
+        tags: Vec<String>,
+        priority: i32,
+    ) -> ObserverHandle<T> {
+        observer.on_attach().await;
+        let registration = {
+            let mut observers = self.inner.observers.lock();
+            let id = self.next_id(&observers);
+            self.register(&mut observers, id, stored, tags, priority)
+        };
+        self.finish_attach(&observer, registration).await
+    }
+
+    /// Takes the next auto-assigned ID, skipping any ID already used by an observer in the
+    /// locked observer list.
+    fn next_id(&self, observers: &[ObserverEntry<T>]) -> u64 {
         let mut next_id = self.inner.next_observer_id.lock();
-        let id = *next_id;
-        *next_id += 1;
-        observers.push((id, observer));
+        let mut id = *next_id;
+        while observers.iter().any(|entry| entry.id == id) {
+            id = id.wrapping_add(1);
+        }
+        *next_id = id.wrapping_add(1);
+        id
+    }
+
+    /// Adds a new entry with the given ID to the locked observer list, returning its
+    /// registration along with the history to replay to it.
+    fn register(
+        &self,
+        observers: &mut Vec<ObserverEntry<T>>,
+        id: u64,
+        stored: ObserverRef<T>,
+        tags: Vec<String>,
+        priority: i32,
+    ) -> Registration<T> {
+        let generation = self.inner.next_generation.fetch_add(1, Ordering::Relaxed);
+        observers.push(ObserverEntry {
+            id,
+            generation,
+            observer: stored,
+            tags,
+            priority,
+            paused: false,
+        });
+        self.inner.latencies.lock().insert(id, None);
+        // The history is snapshotted under the observers lock, which `notify` also holds
+        // while recording, so every event is either replayed or delivered live exactly once.
+        Registration {
+            id,
+            generation,
+            replay: self.inner.history.as_ref().map(History::snapshot),
+        }
+    }
+
+    /// Creates the handle for a freshly registered observer and replays the history to it.
+    async fn finish_attach(
+        &self,
+        observer: &Arc<dyn Observer<T>>,
+        registration: Registration<T>,
+    ) -> ObserverHandle<T> {
+        let Registration {
+            id,
+            generation,
+            replay,
+        } = registration;
         #[cfg(feature = "logging")]
         info!("Attached new observer with ID {}.", id);
+        #[cfg(feature = "metrics")]
+        self.inner.metrics.record_attach();
+        self.inner.emit_lifecycle(id, LifecycleKind::Attached);
 
-        ObserverHandle {
+        // Create the handle before replaying so that cancelling `attach` still detaches.
+        let handle = ObserverHandle {
             id,
+            generation,
             subject_weak: Arc::downgrade(&self.inner),
+        };
+        for event in replay.iter().flatten() {
+            let _ = self.deliver(id, observer, Payload::Borrowed(event)).await;
         }
+        handle
     }
 
     /// Explicitly detaches an `Observer` from the `Subject` using its handle.
     ///
     /// This method consumes the handle and returns `true` if the observer was found
-    /// and detached, `false` otherwise.
-    pub fn detach(&self, handle: ObserverHandle<T>) -> bool {
-        let mut observers = self.inner.observers.lock();
-        if let Some(index) = observers.iter().position(|(id, _)| *id == handle.id) {
-            observers.remove(index);
+    /// and detached, `false` otherwise. The observer's `on_detach` hook is awaited
+    /// before returning.
+    pub async fn detach(&self, mut handle: ObserverHandle<T>) -> bool {
+        // Disarmed first, so dropping the handle later can't touch a newer registration.
+        handle.subject_weak = Weak::new();
+        if let Some(observer) = self.inner.remove(handle.id, Some(handle.generation)) {
             #[cfg(feature = "logging")]
             info!("Observer with ID {} explicitly detached.", handle.id);
+            if let Some(observer) = observer.upgrade() {
+                observer.on_detach().await;
+            }
             true
         } else {
             #[cfg(feature = "logging")]
@@ -110,27 +1049,983 @@ impl<T: Send + Sync + 'static> Subject<T> {
         }
     }
 
+    /// Removes the observer with the given ID and hands it back to the caller.
+    ///
+    /// This is useful for re-attaching an observer to a different `Subject`. Like
+    /// [`Subject::drain`], the `on_detach` hook is not run, and the observer's outstanding
+    /// `ObserverHandle` becomes a no-op. Returns `None` if no observer with that ID is
+    /// attached, or if it was held weakly and has already been dropped.
+    pub fn take(&self, id: u64) -> Option<Arc<dyn Observer<T>>> {
+        let observer = self.inner.remove(id, None)?;
+        #[cfg(feature = "logging")]
+        info!("Observer with ID {} taken out of the subject.", id);
+        observer.upgrade()
+    }
+
+    /// Detaches every observer from the `Subject`, returning how many were removed.
+    ///
+    /// The `on_detach` hooks of the removed observers are awaited concurrently. Any
+    /// outstanding `ObserverHandle`s become no-ops, so dropping them later does nothing.
+    pub async fn detach_all(&self) -> usize {
+        let removed = self.drain();
+        futures::future::join_all(removed.iter().map(|observer| observer.on_detach())).await;
+        removed.len()
+    }
+
+    /// Removes every observer from the `Subject` and hands them back to the caller.
+    ///
+    /// Unlike [`Subject::detach_all`], the `on_detach` hooks are not run, leaving teardown
+    /// to the caller. Any outstanding `ObserverHandle`s become no-ops. Weakly-held observers
+    /// that have already been dropped are removed but not returned.
+    pub fn drain(&self) -> Vec<Arc<dyn Observer<T>>> {
+        let drained = std::mem::take(&mut *self.inner.observers.lock());
+        self.inner.unique_keys.lock().clear();
+        self.inner.latencies.lock().clear();
+        self.inner.payload_caps.lock().clear();
+        for waiter in self
+            .inner
+            .detach_waiters
+            .lock()
+            .drain()
+            .flat_map(|(_, waiters)| waiters)
+        {
+            let _ = waiter.send(());
+        }
+        for entry in &drained {
+            self.inner.emit_lifecycle(entry.id, LifecycleKind::Detached);
+        }
+        #[cfg(feature = "logging")]
+        info!("Removed all {} observers.", drained.len());
+        #[cfg(feature = "metrics")]
+        self.inner.metrics.record_detach(drained.len() as u64);
+        drained
+            .into_iter()
+            .filter_map(|entry| entry.observer.upgrade())
+            .collect()
+    }
+
+    /// Removes every observer for which `f` returns `false`, returning how many were removed.
+    ///
+    /// `f` receives each observer's ID and the observer itself. It runs without any lock held,
+    /// so it may call back into the `Subject`; observers attached while `retain` runs are
+    /// kept. Weakly-held observers that have already been dropped are removed and counted
+    /// without being passed to `f`. As with [`Subject::drain`], the `on_detach` hooks are
+    /// not run and the removed observers' handles become no-ops.
+    pub fn retain(&self, f: impl Fn(u64, &Arc<dyn Observer<T>>) -> bool) -> usize {
+        let snapshot: Vec<_> = self
+            .inner
+            .observers
+            .lock()
+            .iter()
+            .map(|entry| (entry.id, entry.observer.upgrade()))
+            .collect();
+        let rejected: Vec<u64> = snapshot
+            .into_iter()
+            .filter(|(id, observer)| !observer.as_ref().is_some_and(|observer| f(*id, observer)))
+            .map(|(id, _)| id)
+            .collect();
+
+        let mut removed = Vec::new();
+        self.inner.observers.lock().retain(|entry| {
+            let keep = !rejected.contains(&entry.id);
+            if !keep {
+                removed.push(entry.id);
+            }
+            keep
+        });
+        for &id in &removed {
+            self.inner.forget(id);
+            self.inner.emit_lifecycle(id, LifecycleKind::Detached);
+        }
+        let removed = removed.len();
+        #[cfg(feature = "logging")]
+        info!("Removed {} observers rejected by the predicate.", removed);
+        #[cfg(feature = "metrics")]
+        self.inner.metrics.record_detach(removed as u64);
+        removed
+    }
+
+    /// Waits until the observer with the given ID is no longer attached.
+    ///
+    /// Resolves immediately if no observer with that ID is attached, and otherwise once it
+    /// is removed by any means: explicit detachment, a dropped handle, [`Subject::take`],
+    /// [`Subject::drain`] or pruning after a weakly-held observer was dropped. This replaces
+    /// polling [`Subject::observer_count`] in tests and coordination code.
+    pub async fn wait_for_detach(&self, id: u64) {
+        let detached = {
+            // Registering under the observers lock pairs with the removal paths, which take
+            // the same lock to remove the entry before waking the waiters.
+            let observers = self.inner.observers.lock();
+            if !observers.iter().any(|entry| entry.id == id) {
+                return;
+            }
+            let (sender, receiver) = oneshot::channel();
+            self.inner
+                .detach_waiters
+                .lock()
+                .entry(id)
+                .or_default()
+                .push(sender);
+            receiver
+        };
+        let _ = detached.await;
+    }
+
+    /// Pauses the observer with the given ID, returning `false` if it isn't attached.
+    ///
+    /// A paused observer keeps its registration, ID and position but is skipped by every
+    /// notification until it is resumed.
+    pub fn pause(&self, id: u64) -> bool {
+        self.set_paused(id, true)
+    }
+
+    /// Resumes a paused observer, returning `false` if it isn't attached.
+    pub fn resume(&self, id: u64) -> bool {
+        self.set_paused(id, false)
+    }
+
+    fn set_paused(&self, id: u64, paused: bool) -> bool {
+        let mut observers = self.inner.observers.lock();
+        let Some(entry) = observers.iter_mut().find(|entry| entry.id == id) else {
+            return false;
+        };
+        entry.paused = paused;
+        #[cfg(feature = "logging")]
+        info!(
+            "Observer with ID {} {}.",
+            id,
+            if paused { "paused" } else { "resumed" }
+        );
+        true
+    }
+
+    /// Returns the number of attached observers, including paused ones.
+    pub fn observer_count(&self) -> usize {
+        self.inner.observers.lock().len()
+    }
+
+    /// Sets the handler invoked whenever an observer's `update` returns an error.
+    ///
+    /// The handler receives the event, the failing observer's ID and the error, which gives
+    /// a single place to capture failed deliveries for inspection or retry instead of
+    /// handling errors in every observer. It runs inline during the notification, so it
+    /// should be quick. Panics and timeouts are not routed here. Setting a new handler
+    /// replaces the previous one. When a batch fails, the handler is invoked for every event
+    /// of the batch.
+    pub fn on_dead_letter(
+        &self,
+        handler: impl Fn(&T, u64, &ObserverError) + Send + Sync + 'static,
+    ) {
+        *self.inner.dead_letter.lock() = Some(Arc::new(handler));
+    }
+
+    /// Sets the handler invoked whenever an observer's `update` fails.
+    ///
+    /// Unlike the reports logged with the `logging` feature, this handler is always
+    /// available, so failures stay visible without depending on `tracing`. It receives the
+    /// observer's ID and the error for every failed update, including panics and timeouts,
+    /// which the dead-letter handler doesn't see. Failures inside the background tasks of
+    /// decorators such as `DebounceObserver` never reach the `Subject` and are not reported
+    /// here. The handler runs inline during the notification, so it should be quick.
+    /// Setting a new handler replaces the previous one.
+    pub fn on_error(&self, handler: impl Fn(ObserverErrorContext<'_>) + Send + Sync + 'static) {
+        *self.inner.on_error.lock() = Some(Arc::new(handler));
+    }
+
+    /// Sets the handler invoked whenever an observer is attached or detached.
+    ///
+    /// The handler receives an [`ObserverLifecycleEvent`] for every attach and for every
+    /// removal, whether explicit, through a dropped handle, [`Subject::take`],
+    /// [`Subject::drain`] or [`Subject::retain`], or by pruning a dropped weak observer.
+    /// This makes it possible to keep e.g. a live dashboard of the observer set. The handler
+    /// runs synchronously once the observer list is unlocked, so it may query the `Subject`,
+    /// but it should be quick; forwarding the event into a channel is the intended use.
+    /// Without a handler, no events are produced. Setting a new handler replaces the
+    /// previous one.
+    pub fn on_lifecycle(&self, handler: impl Fn(ObserverLifecycleEvent) + Send + Sync + 'static) {
+        *self.inner.lifecycle.lock() = Some(Arc::new(handler));
+    }
+
+    /// Returns the IDs of the attached observers, including paused ones, in ascending order.
+    ///
+    /// Together with [`Subject::notify_one`] and [`Subject::pause`], this allows building a
+    /// small control surface over the observer set. The result is a snapshot and may be
+    /// momentarily stale if observers are attached or detached concurrently.
+    pub fn observer_ids(&self) -> Vec<u64> {
+        let mut ids: Vec<u64> = self
+            .inner
+            .observers
+            .lock()
+            .iter()
+            .map(|entry| entry.id)
+            .collect();
+        // Caller-chosen IDs and wrapped auto-assigned ones don't follow attach order.
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Returns the number of attached observers that are not paused.
+    pub fn active_observer_count(&self) -> usize {
+        self.inner
+            .observers
+            .lock()
+            .iter()
+            .filter(|entry| !entry.paused)
+            .count()
+    }
+
+    /// Returns a snapshot of the `Subject`'s runtime metrics.
+    #[cfg(feature = "metrics")]
+    pub fn metrics(&self) -> SubjectMetricsSnapshot {
+        self.inner.metrics.snapshot(self.observer_count())
+    }
+
     /// Notifies all attached observers of an event.
     ///
     /// The `notify` method takes data by reference and runs each observer's `update` method
     /// concurrently using `futures::future::join_all`. This ensures that a slow observer
-    /// does not block others.
+    /// does not block others. Observers attached with different priorities are notified
+    /// tier by tier, see [`Subject::attach_with_priority`].
+    ///
+    /// `notify` only returns once every observer has finished, so the fan-out completes
+    /// exactly as fast as its slowest observer. With observers that block on a bounded
+    /// channel, a single slow consumer therefore slows the producer down instead of events
+    /// being dropped. Where that is not wanted, `Subject::notify_all_or_timeout` reports
+    /// which observers were still blocked after a time budget.
+    ///
+    /// An error returned by an observer's `update` is reported, and a panic inside it is
+    /// caught and reported instead of unwinding through `notify`, so the remaining observers
+    /// still receive the event.
     pub async fn notify(&self, data: &T) {
-        let observer_arcs: Vec<Arc<dyn Observer<T>>> = {
+        self.notify_payload(Payload::Borrowed(data)).await;
+    }
+
+    /// Notifies all attached observers of an event from synchronous code, blocking the
+    /// current thread until the notification has finished.
+    ///
+    /// This bridges callers outside of any async context, such as a plain `std::thread` or a
+    /// callback from a C library, into the `Subject`. The notification is driven on
+    /// `runtime`, so observers relying on Tokio timers or IO work as usual; obtain the handle
+    /// with `tokio::runtime::Handle::current()` inside the runtime and hand it to the
+    /// synchronous code. Otherwise this behaves exactly like [`Subject::notify`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within an asynchronous execution context, such as a task on a
+    /// Tokio runtime, where blocking the thread could deadlock the runtime.
+    #[cfg(feature = "tokio")]
+    pub fn notify_blocking(&self, runtime: &tokio::runtime::Handle, data: &T) {
+        runtime.block_on(self.notify(data));
+    }
+
+    /// Notifies all attached observers of an event, running each observer's `update` as its
+    /// own Tokio task.
+    ///
+    /// [`Subject::notify`] drives every update within the calling task, so observers only
+    /// run concurrently, never in parallel. Spawning them lets CPU-heavy or blocking-ish
+    /// observers run on separate worker threads, at the cost of a task per observer. The
+    /// event is shared with the tasks through the `Arc`. Priority tiers are still notified
+    /// one after another, while the [`NotifyMode`] doesn't apply. The report lists each
+    /// observer's result as with [`Subject::notify_acked`]; a task that fails to join, e.g.
+    /// because the runtime is shutting down, is reported as `ObserverError::Other`. Dropping
+    /// the returned future aborts the running tasks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside the context of a Tokio runtime.
+    #[cfg(feature = "tokio")]
+    pub async fn notify_spawned(&self, data: Arc<T>) -> AckReport {
+        let Some(_in_flight) = self.inner.begin_notification() else {
+            return AckReport::default();
+        };
+        let size = self.inner.payload_size(Payload::Shared(&data));
+        let observer_arcs = {
+            let observers = self.inner.observers.lock();
+            if let Some(history) = &self.inner.history {
+                history.record(&data);
+            }
+            self.select(observers, size, |_| true)
+        }; // The lock is dropped here
+        #[cfg(feature = "metrics")]
+        self.inner.metrics.record_notification();
+
+        let mut report = AckReport::default();
+        for tier in observer_arcs {
+            let mut tasks = tokio::task::JoinSet::new();
+            let mut ids = Vec::with_capacity(tier.len());
+            for (id, observer) in tier {
+                let subject = self.clone();
+                let data = Arc::clone(&data);
+                let task = tasks.spawn(async move {
+                    subject.deliver(id, &observer, Payload::Shared(&data)).await
+                });
+                ids.push((task.id(), id));
+            }
+
+            let mut joined = HashMap::with_capacity(ids.len());
+            while let Some(outcome) = tasks.join_next_with_id().await {
+                let (task_id, result) = match outcome {
+                    Ok((task_id, result)) => (task_id, result),
+                    Err(error) => (error.id(), Err(ObserverError::other(error))),
+                };
+                joined.insert(task_id, result);
+            }
+            // Report the tier in attach order rather than completion order.
+            for (task_id, id) in ids {
+                if let Some(result) = joined.remove(&task_id) {
+                    report.results.push((id, result));
+                }
+            }
+        }
+        report
+    }
+
+    /// Notifies all attached observers of an event shared through an `Arc`.
+    ///
+    /// Each observer receives the event through [`Observer::update_shared`], so observers
+    /// that keep or re-forward events can clone the `Arc` instead of the data. This pays off
+    /// for large payloads fanned out to many such observers; when observers only read the
+    /// event, plain [`Subject::notify`] avoids the allocation and is the better choice.
+    /// Otherwise this behaves exactly like `notify`.
+    pub async fn notify_shared(&self, data: Arc<T>) {
+        self.notify_payload(Payload::Shared(&data)).await;
+    }
+
+    /// Notifies all attached observers of an event and reports whether each one handled it.
+    ///
+    /// This lets a producer wait for a specific sink, e.g. one persisting the event to disk,
+    /// and check that it succeeded before moving on. The report contains an entry for every
+    /// observer that was notified; paused observers are absent. Otherwise this
+    /// behaves exactly like [`Subject::notify`], so failures are still reported and passed to
+    /// the dead-letter handler. If the `Subject` is shutting down, the report is empty.
+    pub async fn notify_acked(&self, data: &T) -> AckReport {
+        AckReport {
+            results: self.notify_payload(Payload::Borrowed(data)).await,
+        }
+    }
+
+    /// Notifies all attached observers of a batch of events in a single fan-out.
+    ///
+    /// Each observer receives the whole batch through [`Observer::update_batch`], whose
+    /// default implementation falls back to calling `update` for each event. The events are
+    /// recorded in the history buffer individually, so they are replayed to new observers one
+    /// at a time. An empty batch notifies nobody. Otherwise this behaves exactly like
+    /// [`Subject::notify`].
+    pub async fn notify_batch(&self, data: &[T]) {
+        if data.is_empty() {
+            return;
+        }
+        self.notify_payload(Payload::Batch(data)).await;
+    }
+
+    async fn notify_payload(&self, data: Payload<'_, T>) -> Vec<(u64, Result<(), ObserverError>)> {
+        let Some(_in_flight) = self.inner.begin_notification() else {
+            return Vec::new();
+        };
+        let size = self.inner.payload_size(data);
+        let observer_arcs = {
+            let observers = self.inner.observers.lock();
+            if let Some(history) = &self.inner.history {
+                for event in data.events() {
+                    history.record(event);
+                }
+            }
+            self.select(observers, size, |_| true)
+        }; // The lock is dropped here
+        #[cfg(feature = "metrics")]
+        self.inner.metrics.record_notification();
+
+        self.deliver_all(observer_arcs, data).await
+    }
+
+    /// Notifies only the observers carrying `tag` of an event.
+    ///
+    /// Observers without that tag, including every observer attached through plain
+    /// [`Subject::attach`], are skipped. Group notifications are not recorded in the
+    /// history buffer, since they don't reach every observer.
+    pub async fn notify_group(&self, tag: &str, data: &T) {
+        let Some(_in_flight) = self.inner.begin_notification() else {
+            return;
+        };
+        let size = self.inner.payload_size(Payload::Borrowed(data));
+        let observer_arcs = {
+            let observers = self.inner.observers.lock();
+            self.select(observers, size, |entry| {
+                entry.tags.iter().any(|entry_tag| entry_tag == tag)
+            })
+        }; // The lock is dropped here
+        #[cfg(feature = "metrics")]
+        self.inner.metrics.record_notification();
+
+        self.deliver_all(observer_arcs, Payload::Borrowed(data))
+            .await;
+    }
+
+    /// Notifies only the observers accepted by `predicate` of an event.
+    ///
+    /// The predicate receives each active observer's ID and the observer itself and decides
+    /// inclusion for this one notification, e.g. to suppress a transient set of observers.
+    /// It runs without the lock held. The selected observers are notified as by
+    /// [`Subject::notify`], but, as with [`Subject::notify_group`], the event is not
+    /// recorded in the history buffer.
+    pub async fn notify_filtered(
+        &self,
+        data: &T,
+        predicate: impl Fn(u64, &Arc<dyn Observer<T>>) -> bool,
+    ) {
+        let Some(_in_flight) = self.inner.begin_notification() else {
+            return;
+        };
+        let size = self.inner.payload_size(Payload::Borrowed(data));
+        let observer_arcs = {
+            let observers = self.inner.observers.lock();
+            self.select(observers, size, |_| true)
+        }; // The lock is dropped here
+        let observer_arcs: Vec<Tier<T>> = observer_arcs
+            .into_iter()
+            .map(|tier| {
+                tier.into_iter()
+                    .filter(|(id, observer)| predicate(*id, observer))
+                    .collect::<Tier<T>>()
+            })
+            .filter(|tier| !tier.is_empty())
+            .collect();
+        #[cfg(feature = "metrics")]
+        self.inner.metrics.record_notification();
+
+        self.deliver_all(observer_arcs, Payload::Borrowed(data))
+            .await;
+    }
+
+    /// Notifies all attached observers of an event, abandoning the fan-out once `token` is
+    /// cancelled.
+    ///
+    /// Each observer's `update` is raced against the token: updates still running when it
+    /// is cancelled are dropped and reported as cancelled, while those that already finished
+    /// are reported as completed. If the token is cancelled before the call, no observer is
+    /// notified. A cancelled observer may have partially completed its work, e.g. sent a
+    /// request whose response was never awaited, so this is meant for shutdown rather than
+    /// for routine flow control. The event is recorded in the history buffer either way.
+    #[cfg(feature = "tokio")]
+    pub async fn notify_cancellable(&self, data: &T, token: CancellationToken) -> NotifyReport {
+        self.notify_racing(data, || token.cancelled(), || token.is_cancelled())
+            .await
+    }
+
+    /// Notifies all attached observers of an event, abandoning the fan-out at `deadline`.
+    ///
+    /// This puts a single wall-clock budget on the whole notification, e.g. for a request
+    /// handler that must respond in time no matter how slow its sinks are. Observers that
+    /// finished by the deadline are reported as completed; those still running are dropped
+    /// and reported as cancelled. The caveats of [`Subject::notify_cancellable`] about
+    /// partially completed work apply. The deadline is measured with the Tokio timer, so
+    /// this must be called from within a Tokio runtime.
+    #[cfg(feature = "tokio")]
+    pub async fn notify_by_deadline(&self, data: &T, deadline: std::time::Instant) -> NotifyReport {
+        let deadline = tokio::time::Instant::from_std(deadline);
+        self.notify_racing(
+            data,
+            || tokio::time::sleep_until(deadline),
+            || tokio::time::Instant::now() >= deadline,
+        )
+        .await
+    }
+
+    /// Notifies all attached observers of an event, giving the fan-out at most `budget` to
+    /// complete.
+    ///
+    /// Observers still running when the budget is spent, typically blocked on a full
+    /// channel, are abandoned and reported as cancelled, so a producer can tell which sinks
+    /// are the bottleneck and adapt, e.g. by slowing down or shedding load. Otherwise this
+    /// behaves exactly like [`Subject::notify_by_deadline`] with a deadline `budget` from
+    /// now. A budget too large to express as a deadline, such as `Duration::MAX`, never runs
+    /// out.
+    #[cfg(feature = "tokio")]
+    pub async fn notify_all_or_timeout(&self, data: &T, budget: Duration) -> NotifyReport {
+        match Instant::now().checked_add(budget) {
+            Some(deadline) => self.notify_by_deadline(data, deadline).await,
+            None => {
+                self.notify_racing(data, futures::future::pending::<()>, || false)
+                    .await
+            }
+        }
+    }
+
+    /// Notifies all attached observers of an event, spreading their start times across
+    /// `spread`.
+    ///
+    /// Launching hundreds of observers at once can hammer a downstream they share. Here the
+    /// observers still run concurrently, but each one sleeps first, with start times evenly
+    /// distributed over the `spread` window. Priority tiers are still notified one after
+    /// another, each spread over its own window. Otherwise this behaves exactly like
+    /// [`Subject::notify`]. The delays use the Tokio timer, so this must be called from
+    /// within a Tokio runtime.
+    #[cfg(feature = "tokio")]
+    pub async fn notify_staggered(&self, data: &T, spread: Duration) {
+        let Some(_in_flight) = self.inner.begin_notification() else {
+            return;
+        };
+        let size = self.inner.payload_size(Payload::Borrowed(data));
+        let observer_arcs = {
+            let observers = self.inner.observers.lock();
+            if let Some(history) = &self.inner.history {
+                history.record(data);
+            }
+            self.select(observers, size, |_| true)
+        }; // The lock is dropped here
+        #[cfg(feature = "metrics")]
+        self.inner.metrics.record_notification();
+
+        for tier in observer_arcs {
+            let count = u32::try_from(tier.len()).unwrap_or(u32::MAX);
+            self.run_all(tier.into_iter().zip(0..).map(|((id, observer), index)| {
+                let delay = spread / count * index;
+                async move {
+                    tokio::time::sleep(delay).await;
+                    let _ = self.deliver(id, &observer, Payload::Borrowed(data)).await;
+                }
+            }))
+            .await;
+        }
+    }
+
+    /// Notifies all attached observers, racing each update against the future returned by
+    /// `stop`. Observers are not started once `stopped` returns `true`.
+    #[cfg(feature = "tokio")]
+    async fn notify_racing<S: Future>(
+        &self,
+        data: &T,
+        stop: impl Fn() -> S,
+        stopped: impl Fn() -> bool,
+    ) -> NotifyReport {
+        let Some(_in_flight) = self.inner.begin_notification() else {
+            return NotifyReport::default();
+        };
+        let size = self.inner.payload_size(Payload::Borrowed(data));
+        let observer_arcs = {
             let observers = self.inner.observers.lock();
-            observers.iter().map(|(_, obs)| Arc::clone(obs)).collect()
+            if let Some(history) = &self.inner.history {
+                history.record(data);
+            }
+            self.select(observers, size, |_| true)
         }; // The lock is dropped here
 
+        if stopped() {
+            return NotifyReport {
+                completed: Vec::new(),
+                cancelled: observer_arcs
+                    .into_iter()
+                    .flatten()
+                    .map(|(id, _)| id)
+                    .collect(),
+            };
+        }
+        #[cfg(feature = "metrics")]
+        self.inner.metrics.record_notification();
+
+        let (stop, stopped) = (&stop, &stopped);
+        let mut outcomes = Vec::new();
+        for tier in observer_arcs {
+            outcomes.extend(
+                self.run_all(tier.into_iter().map(|(id, observer)| async move {
+                    // Observers queued behind the stop, in a later tier or in sequential
+                    // mode, never start.
+                    if stopped() {
+                        return (id, false);
+                    }
+                    let delivery = self.deliver(id, &observer, Payload::Borrowed(data));
+                    let stop = stop();
+                    futures::pin_mut!(delivery, stop);
+                    let completed = matches!(
+                        futures::future::select(delivery, stop).await,
+                        futures::future::Either::Left(_)
+                    );
+                    (id, completed)
+                }))
+                .await,
+            );
+        }
+
+        let mut report = NotifyReport::default();
+        for (id, completed) in outcomes {
+            if completed {
+                report.completed.push(id);
+            } else {
+                report.cancelled.push(id);
+            }
+        }
         #[cfg(feature = "logging")]
-        trace!("Notifying {} observers...", observer_arcs.len());
-        let mut futures = Vec::new();
-        for observer in observer_arcs {
-            let future = async move {
-                observer.update(data).await;
+        debug!(
+            "Notification finished: {} completed, {} cancelled.",
+            report.completed.len(),
+            report.cancelled.len()
+        );
+        report
+    }
+
+    /// Notifies a single observer, identified by its ID, of an event.
+    ///
+    /// Returns `false` if no observer with that ID is attached, it is paused, the event
+    /// exceeds its payload size cap, or the `Subject` is shutting down. This is useful for
+    /// targeted re-delivery, such as sending a catch-up snapshot to an observer that just
+    /// reconnected, without disturbing the others.
+    pub async fn notify_one(&self, id: u64, data: &T) -> bool {
+        let Some(_in_flight) = self.inner.begin_notification() else {
+            return false;
+        };
+        let size = self.inner.payload_size(Payload::Borrowed(data));
+        let observer = {
+            let observers = self.inner.observers.lock();
+            observers
+                .iter()
+                .find(|entry| entry.id == id && !entry.paused)
+                .and_then(|entry| entry.observer.upgrade())
+        }; // The lock is dropped here
+        let observer = observer.filter(|_| !self.inner.exceeds_cap(id, size));
+
+        let Some(observer) = observer else {
+            #[cfg(feature = "logging")]
+            debug!("Could not find observer with ID {} to notify.", id);
+            return false;
+        };
+        #[cfg(feature = "metrics")]
+        self.inner.metrics.record_notification();
+        let _ = self.deliver(id, &observer, Payload::Borrowed(data)).await;
+        true
+    }
+
+    /// Stops accepting notifications and waits for the ones in flight to finish.
+    ///
+    /// Once this is called, every notification method returns immediately without
+    /// delivering anything: `notify`, `notify_shared` and `notify_group` silently drop the
+    /// event, `notify_one` returns `false` and `notify_cancellable` reports no observers.
+    /// Rejected events are not queued or recorded in the history. Notifications that were
+    /// already running are awaited, so a server can flush its fan-out before exiting.
+    /// Observers stay attached and can still be detached afterwards. Shutting down is
+    /// permanent and affects every clone of the `Subject`.
+    pub async fn shutdown(&self) {
+        self.inner.shutting_down.store(true, Ordering::SeqCst);
+        #[cfg(feature = "logging")]
+        info!("Subject shutting down, waiting for in-flight notifications.");
+        self.wait_idle().await;
+    }
+
+    /// Returns `true` once [`Subject::shutdown`] has been called.
+    pub fn is_shutting_down(&self) -> bool {
+        self.inner.shutting_down.load(Ordering::SeqCst)
+    }
+
+    /// Enables or disables notifications on the `Subject`.
+    ///
+    /// While disabled, every notification method returns immediately, exactly as after
+    /// [`Subject::shutdown`]: events are dropped without touching the observer list or the
+    /// history. Observers stay attached and receive events again once the `Subject` is
+    /// re-enabled, which makes this a cheap way to mute a noisy subject at runtime. A
+    /// `Subject` starts out enabled, and the setting affects every clone of it.
+    pub fn set_enabled(&self, enabled: bool) {
+        self.inner.enabled.store(enabled, Ordering::Relaxed);
+        #[cfg(feature = "logging")]
+        info!(
+            "Subject notifications {}.",
+            if enabled { "enabled" } else { "disabled" }
+        );
+    }
+
+    /// Returns `true` unless notifications have been disabled with [`Subject::set_enabled`].
+    pub fn is_enabled(&self) -> bool {
+        self.inner.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Returns the moving average of the observer's recent `update` durations.
+    ///
+    /// Every update the observer runs, whether it succeeds, fails or times out, is folded
+    /// into an exponential moving average, so the value reflects recent behavior rather
+    /// than the whole history. It can drive adaptive timeouts, e.g. a multiple of the
+    /// average, or feed a dashboard. Returns `None` if no observer with that ID is attached
+    /// or it hasn't been updated yet.
+    pub fn observer_latency(&self, id: u64) -> Option<Duration> {
+        self.inner.latencies.lock().get(&id).copied().flatten()
+    }
+
+    /// Returns the number of notifications currently being delivered.
+    ///
+    /// Every notification method counts while it runs, including one whose future is
+    /// dropped partway, which stops counting once dropped. The value may change as soon as
+    /// it is read, so it is meant for coordination hints and monitoring; use
+    /// [`Subject::wait_idle`] to actually wait for the fan-out to finish.
+    pub fn notify_in_flight(&self) -> usize {
+        self.inner.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Waits until no notification is being delivered.
+    ///
+    /// Without a prior [`Subject::shutdown`], new notifications may start as soon as this
+    /// resolves, so it only guarantees that the `Subject` was idle at some point.
+    pub async fn wait_idle(&self) {
+        let idle = {
+            // Checking under the waiters lock pairs with `InFlight::drop`, which takes the
+            // same lock after the count reaches zero, so no wake-up can be missed.
+            let mut waiters = self.inner.idle_waiters.lock();
+            if self.inner.in_flight.load(Ordering::SeqCst) == 0 {
+                return;
+            }
+            let (sender, receiver) = oneshot::channel();
+            waiters.push(sender);
+            receiver
+        };
+        let _ = idle.await;
+    }
+
+    /// Clones out the active observers matching `filter` so they can be notified without
+    /// the lock, grouped into tiers by descending priority. Paused observers and those whose
+    /// size cap a payload of `size` exceeds are never selected, and weakly-held observers
+    /// that have been dropped are pruned from the list. Takes the lock so it can release it
+    /// before reporting the pruned observers to the lifecycle handler.
+    fn select(
+        &self,
+        mut observers: MutexGuard<'_, Vec<ObserverEntry<T>>>,
+        size: Option<usize>,
+        filter: impl Fn(&ObserverEntry<T>) -> bool,
+    ) -> Vec<Tier<T>> {
+        let mut selected = Vec::new();
+        let mut pruned = Vec::new();
+        #[cfg(feature = "metrics")]
+        let before = observers.len();
+        observers.retain(|entry| {
+            let Some(observer) = entry.observer.upgrade() else {
+                #[cfg(feature = "logging")]
+                info!(
+                    "Observer with ID {} was dropped and has been pruned.",
+                    entry.id
+                );
+                self.inner.forget(entry.id);
+                pruned.push(entry.id);
+                return false;
             };
-            futures.push(future);
+            if !entry.paused && !self.inner.exceeds_cap(entry.id, size) && filter(entry) {
+                selected.push((entry.priority, entry.id, observer));
+            }
+            true
+        });
+        #[cfg(feature = "metrics")]
+        self.inner
+            .metrics
+            .record_detach((before - observers.len()) as u64);
+        drop(observers);
+        for id in pruned {
+            self.inner.emit_lifecycle(id, LifecycleKind::Detached);
+        }
+
+        // The sort is stable, so observers keep their attach order within a tier.
+        selected.sort_by_key(|(priority, ..)| std::cmp::Reverse(*priority));
+        let mut tiers: Vec<(i32, Tier<T>)> = Vec::new();
+        for (priority, id, observer) in selected {
+            match tiers.last_mut() {
+                Some((tier_priority, tier)) if *tier_priority == priority => {
+                    tier.push((id, observer));
+                }
+                _ => tiers.push((priority, vec![(id, observer)])),
+            }
+        }
+        tiers.into_iter().map(|(_, tier)| tier).collect()
+    }
+
+    /// Runs the given observers' `update` methods tier by tier, each tier according to the
+    /// `Subject`'s [`NotifyMode`], returning each observer's result by ID.
+    async fn deliver_all(
+        &self,
+        observer_arcs: Vec<Tier<T>>,
+        data: Payload<'_, T>,
+    ) -> Vec<(u64, Result<(), ObserverError>)> {
+        #[cfg(feature = "logging")]
+        trace!(
+            "Notifying {} observers...",
+            observer_arcs.iter().map(Vec::len).sum::<usize>()
+        );
+        let mut results = Vec::new();
+        for tier in observer_arcs {
+            let mut futures = Vec::new();
+            for (id, observer) in tier {
+                let future = async move { (id, self.deliver(id, &observer, data).await) };
+                futures.push(future);
+            }
+            results.extend(self.run_all(futures).await);
+        }
+        results
+    }
+
+    /// Runs the given delivery futures according to the `Subject`'s [`NotifyMode`].
+    async fn run_all<F: Future>(&self, futures: impl IntoIterator<Item = F>) -> Vec<F::Output> {
+        match self.inner.notify_mode {
+            NotifyMode::Concurrent => futures::future::join_all(futures).await,
+            NotifyMode::Sequential => {
+                let mut outputs = Vec::new();
+                for future in futures {
+                    outputs.push(future.await);
+                }
+                outputs
+            }
         }
-        futures::future::join_all(futures).await;
+    }
+
+    /// Runs a single observer's `update`, catching and reporting a panic, and returns its
+    /// result.
+    ///
+    /// With the `logging` feature, the update runs inside an `observer_update` span carrying
+    /// the observer's ID, and its duration is logged when it finishes.
+    async fn deliver(
+        &self,
+        id: u64,
+        observer: &Arc<dyn Observer<T>>,
+        data: Payload<'_, T>,
+    ) -> Result<(), ObserverError> {
+        let delivery = async {
+            #[cfg(feature = "metrics")]
+            self.inner.metrics.record_invocation();
+            let update = match data {
+                Payload::Borrowed(data) => observer.update(data),
+                Payload::Shared(data) => observer.update_shared(data),
+                Payload::Batch(data) => observer.update_batch(data),
+            };
+            let update = AssertUnwindSafe(update).catch_unwind();
+            let started = Instant::now();
+            #[cfg(feature = "tokio")]
+            let outcome = match self.inner.default_timeout {
+                Some(timeout) => tokio::time::timeout(timeout, update).await.ok(),
+                None => Some(update.await),
+            };
+            #[cfg(not(feature = "tokio"))]
+            let outcome = Some(update.await);
+            self.inner.record_latency(id, started.elapsed());
+
+            let result = match outcome {
+                Some(Ok(Ok(()))) => Ok(()),
+                Some(Ok(Err(error))) => {
+                    #[cfg(feature = "metrics")]
+                    self.inner.metrics.record_failure();
+                    report_error(id, &error);
+                    let dead_letter = self.inner.dead_letter.lock().clone();
+                    if let Some(dead_letter) = dead_letter {
+                        for event in data.events() {
+                            dead_letter(event, id, &error);
+                        }
+                    }
+                    Err(error)
+                }
+                Some(Err(panic)) => {
+                    #[cfg(feature = "metrics")]
+                    self.inner.metrics.record_failure();
+                    report_panic(id, &*panic);
+                    Err(ObserverError::Panicked)
+                }
+                None => {
+                    #[cfg(feature = "metrics")]
+                    self.inner.metrics.record_failure();
+                    #[cfg(feature = "logging")]
+                    error!("Observer with ID {} timed out during update.", id);
+                    Err(ObserverError::TimedOut)
+                }
+            };
+            if let Err(error) = &result {
+                let on_error = self.inner.on_error.lock().clone();
+                if let Some(on_error) = on_error {
+                    on_error(ObserverErrorContext {
+                        observer_id: id,
+                        error,
+                    });
+                }
+            }
+            result
+        };
+
+        #[cfg(feature = "logging")]
+        let delivery = {
+            let started = Instant::now();
+            async move {
+                let result = delivery.await;
+                debug!("Observer update finished in {:?}.", started.elapsed());
+                result
+            }
+            .instrument(info_span!("observer_update", observer_id = id))
+        };
+
+        delivery.await
+    }
+}
+
+/// Reports an error returned by an observer's update.
+#[cfg_attr(not(feature = "logging"), allow(unused_variables))]
+fn report_error(id: u64, error: &ObserverError) {
+    #[cfg(feature = "logging")]
+    error!("Observer with ID {} failed to handle event: {}", id, error);
+}
+
+/// Reports a panic that was caught while an observer was being updated.
+#[cfg_attr(not(feature = "logging"), allow(unused_variables))]
+fn report_panic(id: u64, payload: &(dyn Any + Send)) {
+    #[cfg(feature = "logging")]
+    error!(
+        "Observer with ID {} panicked during update: {}",
+        id,
+        panic_message(payload)
+    );
+}
+
+/// Extracts a human-readable message from a panic payload.
+#[cfg(feature = "logging")]
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("non-string panic payload")
+}
+
+impl<T: Clone + Send + Sync + 'static> Subject<T> {
+    /// Creates a new `Subject` that keeps the last `capacity` events in a history buffer.
+    ///
+    /// Observers attached later have the buffered events replayed to them on attach.
+    /// Replay never drops or duplicates an event that is notified concurrently, but such
+    /// a live event may reach the new observer before the replay has finished, so
+    /// ordering across the replay boundary is best-effort.
+    pub fn with_history(capacity: usize) -> Self {
+        Self::with_options(SubjectOptions {
+            history: Some(History::new(capacity)),
+            ..SubjectOptions::default()
+        })
+    }
+}
+
+#[cfg(feature = "json")]
+impl<T: serde::Serialize + Send + Sync + 'static> Subject<T> {
+    /// Attaches an `Observer` that is skipped for events larger than `max_payload_bytes`.
+    ///
+    /// Sizes are measured by serializing the event, hence the `T: Serialize` bound and the
+    /// `json` feature: the size of an event is the length of its JSON serialization, and
+    /// that of a batch the sum over its events. An event that fails to serialize counts as
+    /// too large. Every notify method measures each event once, before it selects the
+    /// observers, and only while at least one observer with a cap is attached. Observers
+    /// without a cap always receive the event. History replay on attach is not capped.
+    /// Otherwise this behaves exactly like [`Subject::attach`].
+    pub async fn attach_with_limits(
+        &self,
+        observer: Arc<dyn Observer<T>>,
+        max_payload_bytes: usize,
+    ) -> ObserverHandle<T> {
+        observer.on_attach().await;
+        let registration = {
+            let mut observers = self.inner.observers.lock();
+            let id = self.next_id(&observers);
+            let size_of: fn(&T) -> usize =
+                |data| serde_json::to_vec(data).map_or(usize::MAX, |encoded| encoded.len());
+            self.inner
+                .payload_caps
+                .lock()
+                .insert(id, (max_payload_bytes, size_of));
+            let stored = ObserverRef::Strong(Arc::clone(&observer));
+            self.register(&mut observers, id, stored, Vec::new(), 0)
+        };
+        self.finish_attach(&observer, registration).await
     }
 }
 
@@ -0,0 +1,66 @@
+//! Runtime counters describing how a `Subject` is behaving.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// The counters kept by a `Subject` when the `metrics` feature is enabled.
+#[derive(Debug, Default)]
+pub(crate) struct SubjectMetrics {
+    notifications: AtomicU64,
+    observer_invocations: AtomicU64,
+    observer_failures: AtomicU64,
+    observers_attached: AtomicU64,
+    observers_detached: AtomicU64,
+}
+
+impl SubjectMetrics {
+    pub(crate) fn record_notification(&self) {
+        self.notifications.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_invocation(&self) {
+        self.observer_invocations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_failure(&self) {
+        self.observer_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_attach(&self) {
+        self.observers_attached.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_detach(&self, count: u64) {
+        self.observers_detached.fetch_add(count, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self, observer_count: usize) -> SubjectMetricsSnapshot {
+        SubjectMetricsSnapshot {
+            notifications: self.notifications.load(Ordering::Relaxed),
+            observer_invocations: self.observer_invocations.load(Ordering::Relaxed),
+            observer_failures: self.observer_failures.load(Ordering::Relaxed),
+            observers_attached: self.observers_attached.load(Ordering::Relaxed),
+            observers_detached: self.observers_detached.load(Ordering::Relaxed),
+            observer_count,
+        }
+    }
+}
+
+/// A point-in-time copy of a `Subject`'s metrics, returned by `Subject::metrics`.
+///
+/// The counters are read individually, so under concurrent use they may be slightly out
+/// of step with one another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SubjectMetricsSnapshot {
+    /// The number of notifications issued through the `Subject`.
+    pub notifications: u64,
+    /// The total number of observer `update` calls made across all notifications.
+    pub observer_invocations: u64,
+    /// The number of observer `update` calls that failed, e.g. by panicking.
+    pub observer_failures: u64,
+    /// The total number of observers ever attached.
+    pub observers_attached: u64,
+    /// The total number of observers ever detached, by any means.
+    pub observers_detached: u64,
+    /// The number of observers currently attached, including paused ones.
+    pub observer_count: usize,
+}
@@ -0,0 +1,52 @@
+//! An observer trait built on native `async fn` in traits, without `async_trait` boxing.
+
+use crate::{Observer, ObserverError, ObserverHandle, Subject};
+use async_trait::async_trait;
+use std::future::Future;
+use std::sync::Arc;
+
+/// A counterpart to [`Observer`] whose `update` returns an unboxed future.
+///
+/// Implementations may simply write `async fn update`. Calling a `NativeObserver` through
+/// a concrete type, e.g. from another observer that wraps it, doesn't allocate a boxed
+/// future per event the way an `#[async_trait]` method does.
+///
+/// The `Subject` stores its observers as `dyn Observer<T>`, and a trait returning
+/// `impl Future` can't be made into a trait object, so a `NativeObserver` is attached
+/// through a [`NativeAdapter`]. The adapter boxes the future once at that boundary, which
+/// costs the same as an `#[async_trait]` observer, so `Subject::notify` is no cheaper for
+/// a `NativeObserver`; the saving only applies to direct calls on the concrete type.
+pub trait NativeObserver<T>: Send + Sync {
+    /// Called by the `Subject` when a new event occurs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ObserverError` if the observer failed to handle the event.
+    fn update(&self, data: &T) -> impl Future<Output = Result<(), ObserverError>> + Send;
+}
+
+/// Adapts a [`NativeObserver`] into an [`Observer`].
+///
+/// A blanket `Observer` implementation for every `NativeObserver` would conflict with the
+/// crate's generic observers, hence the wrapper, as with [`SyncAdapter`](crate::SyncAdapter).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NativeAdapter<O>(pub O);
+
+#[async_trait]
+impl<T: Sync, O: NativeObserver<T>> Observer<T> for NativeAdapter<O> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        self.0.update(data).await
+    }
+}
+
+impl<T: Send + Sync + 'static> Subject<T> {
+    /// Attaches a [`NativeObserver`], wrapping it in a [`NativeAdapter`].
+    ///
+    /// Otherwise this behaves exactly like [`Subject::attach`].
+    pub async fn attach_native(
+        &self,
+        observer: impl NativeObserver<T> + 'static,
+    ) -> ObserverHandle<T> {
+        self.attach(Arc::new(NativeAdapter(observer))).await
+    }
+}
@@ -0,0 +1,40 @@
+//! An observer backed by a `tokio::sync::broadcast` channel.
+
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use tokio::sync::broadcast;
+#[cfg(feature = "logging")]
+use tracing::debug;
+
+/// An `Observer` that forwards every event into a `tokio::sync::broadcast` channel.
+///
+/// Every active receiver gets its own copy of each event, which makes this observer
+/// suitable for fanning a single `Subject` out to many independent consumers.
+/// Sending while no receiver is subscribed is not treated as an error: the event is
+/// simply dropped.
+pub struct BroadcastObserver<T> {
+    sender: broadcast::Sender<T>,
+}
+
+impl<T> BroadcastObserver<T> {
+    /// Creates a new `BroadcastObserver` that sends events through `sender`.
+    pub const fn new(sender: broadcast::Sender<T>) -> Self {
+        Self { sender }
+    }
+
+    /// Creates a new receiver that will see every event sent after this call.
+    pub fn subscribe(&self) -> broadcast::Receiver<T> {
+        self.sender.subscribe()
+    }
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync> Observer<T> for BroadcastObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        if self.sender.send(data.clone()).is_err() {
+            #[cfg(feature = "logging")]
+            debug!("Broadcast observer has no active receivers, event dropped.");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+//! An in-memory sink retaining the most recent events.
+
+use crate::history::History;
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+
+/// An `Observer` that keeps the last `capacity` events it received in a ring buffer.
+///
+/// This is a standalone sink, independent of a `Subject`'s replay-on-attach history: keep
+/// the `Arc` used to attach it and query it with [`RingBufferObserver::snapshot`], e.g.
+/// from an admin or debug endpoint. When the buffer is full, the oldest event is evicted.
+pub struct RingBufferObserver<T> {
+    events: History<T>,
+}
+
+impl<T: Clone> RingBufferObserver<T> {
+    /// Creates a new `RingBufferObserver` retaining at most `capacity` events.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            events: History::new(capacity),
+        }
+    }
+
+    /// Returns clones of the retained events, oldest first.
+    pub fn snapshot(&self) -> Vec<T> {
+        self.events.snapshot()
+    }
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync> Observer<T> for RingBufferObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        self.events.record(data);
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+//! A decorator that decouples a slow observer from the notify path with a bounded queue.
+
+use crate::observers::{BackgroundErrors, update_in_background};
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::sync::mpsc;
+#[cfg(feature = "logging")]
+use tracing::debug;
+
+/// Selects what `update` does when the queue of a [`BufferedObserver`] is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// The new event is dropped, so `update` always returns immediately.
+    #[default]
+    DropNewest,
+    /// `update` waits until the queue has room, applying backpressure to `notify`.
+    Block,
+}
+
+/// Selects what happens to queued events when a [`BufferedObserver`] is dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShutdownPolicy {
+    /// Queued events are still delivered before the worker exits.
+    #[default]
+    Flush,
+    /// Queued events are discarded; an update already in progress still completes.
+    Discard,
+}
+
+/// An `Observer` decorator that hands events to the inner observer through a bounded queue.
+///
+/// `update` only enqueues a clone of the event, and a dedicated Tokio task delivers the
+/// queued events to the inner observer one at a time, in order. A slow inner observer
+/// therefore never holds up `notify`; once `capacity` events are waiting, the
+/// [`OverflowPolicy`] decides whether new events are dropped or `update` waits. Errors and
+/// panics of the inner observer happen on the worker task and are logged there, or passed
+/// to the handler set with [`BufferedObserver::with_error_handler`].
+pub struct BufferedObserver<T> {
+    sender: mpsc::Sender<T>,
+    overflow: OverflowPolicy,
+    shutdown: ShutdownPolicy,
+    discard: Arc<AtomicBool>,
+    errors: BackgroundErrors,
+}
+
+impl<T: Send + Sync + 'static> BufferedObserver<T> {
+    /// Creates a new `BufferedObserver` queueing up to `capacity` events for `inner`.
+    ///
+    /// A capacity of zero is treated as one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside the context of a Tokio runtime.
+    pub fn new(inner: Arc<dyn Observer<T>>, capacity: usize) -> Self {
+        let (sender, receiver) = mpsc::channel(capacity.max(1));
+        let discard = Arc::new(AtomicBool::new(false));
+        let errors = BackgroundErrors::default();
+        tokio::spawn(run(inner, receiver, Arc::clone(&discard), errors.clone()));
+        Self {
+            sender,
+            overflow: OverflowPolicy::default(),
+            shutdown: ShutdownPolicy::default(),
+            discard,
+            errors,
+        }
+    }
+}
+
+impl<T> BufferedObserver<T> {
+    /// Sets what `update` does when the queue is full.
+    pub const fn with_overflow(mut self, overflow: OverflowPolicy) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Sets what happens to queued events when the observer is dropped.
+    pub const fn with_shutdown(mut self, shutdown: ShutdownPolicy) -> Self {
+        self.shutdown = shutdown;
+        self
+    }
+
+    /// Sets a handler receiving the errors of the inner observer, a panic as
+    /// [`ObserverError::Panicked`]. Without one, they are only logged.
+    pub fn with_error_handler(
+        self,
+        handler: impl Fn(&ObserverError) + Send + Sync + 'static,
+    ) -> Self {
+        self.errors.set(handler);
+        self
+    }
+}
+
+impl<T> Drop for BufferedObserver<T> {
+    fn drop(&mut self) {
+        if self.shutdown == ShutdownPolicy::Discard {
+            self.discard.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync> Observer<T> for BufferedObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        match self.overflow {
+            OverflowPolicy::DropNewest => match self.sender.try_send(data.clone()) {
+                Ok(()) => Ok(()),
+                Err(mpsc::error::TrySendError::Full(_)) => {
+                    #[cfg(feature = "logging")]
+                    debug!("Buffered observer queue full, event dropped.");
+                    Ok(())
+                }
+                Err(mpsc::error::TrySendError::Closed(_)) => Err(ObserverError::ChannelClosed),
+            },
+            OverflowPolicy::Block => self
+                .sender
+                .send(data.clone())
+                .await
+                .map_err(|_| ObserverError::ChannelClosed),
+        }
+    }
+}
+
+/// The background task delivering queued events to the inner observer in order.
+async fn run<T: Send + Sync>(
+    inner: Arc<dyn Observer<T>>,
+    mut receiver: mpsc::Receiver<T>,
+    discard: Arc<AtomicBool>,
+    errors: BackgroundErrors,
+) {
+    // The queue closes once the observer is dropped, after the remaining events are read.
+    while let Some(event) = receiver.recv().await {
+        if discard.load(Ordering::SeqCst) {
+            break;
+        }
+        update_in_background(&*inner, &event, "Buffered", &errors).await;
+    }
+}
@@ -0,0 +1,168 @@
+//! A decorator that stops calling a failing observer until it has had time to recover.
+
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use futures::FutureExt;
+use parking_lot::Mutex;
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+use std::time::Duration;
+#[cfg(not(feature = "tokio"))]
+use std::time::Instant;
+// With Tokio, the cooldown follows the Tokio clock, so it can be paused in tests.
+#[cfg(feature = "tokio")]
+use tokio::time::Instant;
+#[cfg(feature = "logging")]
+use tracing::{debug, warn};
+
+/// The state of a [`CircuitBreakerObserver`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Events are delivered to the inner observer.
+    Closed,
+    /// The inner observer failed too often; events are skipped until the cooldown elapses.
+    Open,
+    /// The cooldown has elapsed and a single trial event is being delivered.
+    HalfOpen,
+}
+
+struct Breaker {
+    state: CircuitState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+}
+
+/// An `Observer` decorator implementing the circuit-breaker pattern.
+///
+/// After `failure_threshold` consecutive failed updates the circuit opens and events are
+/// skipped, without touching the inner observer, until `cooldown` has elapsed. The next
+/// event is then delivered as a trial: if it succeeds the circuit closes again, otherwise
+/// it reopens for another cooldown. Events arriving while the trial is running are skipped.
+///
+/// An update counts as failed when the inner observer returns an error or panics. Either is
+/// still propagated so the `Subject` reports it as usual, while skipped events succeed.
+pub struct CircuitBreakerObserver<T> {
+    inner: Arc<dyn Observer<T>>,
+    failure_threshold: u32,
+    cooldown: Duration,
+    breaker: Mutex<Breaker>,
+}
+
+impl<T> CircuitBreakerObserver<T> {
+    /// Creates a new `CircuitBreakerObserver` that opens after `failure_threshold`
+    /// consecutive failures of `inner` and stays open for `cooldown`.
+    ///
+    /// A threshold of zero is treated as one.
+    pub fn new(inner: Arc<dyn Observer<T>>, failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            inner,
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            breaker: Mutex::new(Breaker {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                opened_at: None,
+            }),
+        }
+    }
+
+    /// Returns the current state of the circuit, e.g. for a health check.
+    pub fn state(&self) -> CircuitState {
+        self.breaker.lock().state
+    }
+
+    /// Decides whether an event may be delivered, moving an open circuit whose cooldown has
+    /// elapsed to half-open.
+    fn admit(&self) -> bool {
+        let mut breaker = self.breaker.lock();
+        match breaker.state {
+            CircuitState::Closed => true,
+            CircuitState::HalfOpen => false,
+            CircuitState::Open => {
+                let cooled_down = breaker
+                    .opened_at
+                    .is_none_or(|opened_at| opened_at.elapsed() >= self.cooldown);
+                if cooled_down {
+                    breaker.state = CircuitState::HalfOpen;
+                }
+                cooled_down
+            }
+        }
+    }
+
+    fn record_success(&self) {
+        let mut breaker = self.breaker.lock();
+        breaker.state = CircuitState::Closed;
+        breaker.consecutive_failures = 0;
+        breaker.opened_at = None;
+    }
+
+    fn record_failure(&self) {
+        let mut breaker = self.breaker.lock();
+        breaker.consecutive_failures = breaker.consecutive_failures.saturating_add(1);
+        if breaker.state == CircuitState::HalfOpen
+            || breaker.consecutive_failures >= self.failure_threshold
+        {
+            #[cfg(feature = "logging")]
+            warn!(
+                "Circuit opened after {} consecutive failures.",
+                breaker.consecutive_failures
+            );
+            breaker.state = CircuitState::Open;
+            breaker.opened_at = Some(Instant::now());
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for CircuitBreakerObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        if !self.admit() {
+            #[cfg(feature = "logging")]
+            debug!("Circuit open, skipped an event.");
+            return Ok(());
+        }
+
+        // If this update is cancelled mid-flight, a half-open trial must not stay pending
+        // forever, so the guard reopens the circuit unless an outcome was recorded.
+        let mut trial = TrialGuard {
+            observer: self,
+            settled: false,
+        };
+        let update = AssertUnwindSafe(self.inner.update(data)).catch_unwind();
+        let result = update.await;
+        trial.settled = true;
+        match result {
+            Ok(Ok(())) => {
+                self.record_success();
+                Ok(())
+            }
+            Ok(Err(error)) => {
+                self.record_failure();
+                Err(error)
+            }
+            Err(panic) => {
+                self.record_failure();
+                std::panic::resume_unwind(panic);
+            }
+        }
+    }
+}
+
+struct TrialGuard<'a, T> {
+    observer: &'a CircuitBreakerObserver<T>,
+    settled: bool,
+}
+
+impl<T> Drop for TrialGuard<'_, T> {
+    fn drop(&mut self) {
+        if self.settled {
+            return;
+        }
+        let mut breaker = self.observer.breaker.lock();
+        if breaker.state == CircuitState::HalfOpen {
+            breaker.state = CircuitState::Open;
+            breaker.opened_at = Some(Instant::now());
+        }
+    }
+}
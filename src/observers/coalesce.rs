@@ -0,0 +1,138 @@
+//! A decorator that merges pending events by key while the inner observer is busy.
+
+use crate::observers::{BackgroundErrors, update_in_background};
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::sync::Notify;
+
+/// The events waiting for delivery, at most one per key, in the order the keys arrived.
+struct Pending<K, T> {
+    order: VecDeque<K>,
+    latest: HashMap<K, T>,
+}
+
+/// The state shared between the observer and its worker task.
+struct Shared<K, T> {
+    pending: Mutex<Pending<K, T>>,
+    wake: Notify,
+    closed: AtomicBool,
+}
+
+/// An `Observer` decorator that only delivers the latest event per key to the inner
+/// observer.
+///
+/// Events are queued under the key produced by `key_fn`, and a newer event replaces a
+/// pending one with the same key. A background worker delivers the pending events one at
+/// a time, keys in the order they arrived; while the inner observer is busy, new events
+/// keep coalescing with the pending ones. This suits state-sync sinks where only the
+/// latest state of each entity matters. `update` itself never waits for the inner
+/// observer.
+///
+/// The worker runs on a background Tokio task owned by the observer. When the observer is
+/// dropped, the pending events are still delivered before the task exits. Errors and panics
+/// of the inner observer are logged there, or passed to the handler set with
+/// [`CoalescingObserver::with_error_handler`].
+pub struct CoalescingObserver<T, K> {
+    shared: Arc<Shared<K, T>>,
+    key_fn: Box<dyn Fn(&T) -> K + Send + Sync>,
+    errors: BackgroundErrors,
+}
+
+impl<T, K> CoalescingObserver<T, K>
+where
+    T: Send + Sync + 'static,
+    K: Eq + Hash + Clone + Send + 'static,
+{
+    /// Creates a new `CoalescingObserver` delivering to `inner` the latest event per key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside the context of a Tokio runtime.
+    pub fn new(
+        inner: Arc<dyn Observer<T>>,
+        key_fn: impl Fn(&T) -> K + Send + Sync + 'static,
+    ) -> Self {
+        let shared = Arc::new(Shared {
+            pending: Mutex::new(Pending {
+                order: VecDeque::new(),
+                latest: HashMap::new(),
+            }),
+            wake: Notify::new(),
+            closed: AtomicBool::new(false),
+        });
+        let errors = BackgroundErrors::default();
+        tokio::spawn(run(inner, Arc::clone(&shared), errors.clone()));
+        Self {
+            shared,
+            key_fn: Box::new(key_fn),
+            errors,
+        }
+    }
+}
+
+impl<T, K> CoalescingObserver<T, K> {
+    /// Sets a handler receiving the errors of the inner observer, a panic as
+    /// [`ObserverError::Panicked`]. Without one, they are only logged.
+    pub fn with_error_handler(
+        self,
+        handler: impl Fn(&ObserverError) + Send + Sync + 'static,
+    ) -> Self {
+        self.errors.set(handler);
+        self
+    }
+}
+
+#[async_trait]
+impl<T, K> Observer<T> for CoalescingObserver<T, K>
+where
+    T: Clone + Send + Sync,
+    K: Eq + Hash + Clone + Send + Sync,
+{
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        let key = (self.key_fn)(data);
+        {
+            let mut pending = self.shared.pending.lock();
+            if pending.latest.insert(key.clone(), data.clone()).is_none() {
+                pending.order.push_back(key);
+            }
+        }
+        self.shared.wake.notify_one();
+        Ok(())
+    }
+}
+
+impl<T, K> Drop for CoalescingObserver<T, K> {
+    fn drop(&mut self) {
+        self.shared.closed.store(true, Ordering::SeqCst);
+        self.shared.wake.notify_one();
+    }
+}
+
+/// The background task delivering the pending events whenever the inner observer is free.
+async fn run<T: Send + Sync, K: Eq + Hash>(
+    inner: Arc<dyn Observer<T>>,
+    shared: Arc<Shared<K, T>>,
+    errors: BackgroundErrors,
+) {
+    loop {
+        // Events are taken one at a time, so a key keeps coalescing until its turn comes.
+        let next = {
+            let mut pending = shared.pending.lock();
+            let Pending { order, latest } = &mut *pending;
+            order.pop_front().and_then(|key| latest.remove(&key))
+        };
+        let Some(event) = next else {
+            if shared.closed.load(Ordering::SeqCst) {
+                break;
+            }
+            shared.wake.notified().await;
+            continue;
+        };
+        update_in_background(&*inner, &event, "Coalesced", &errors).await;
+    }
+}
@@ -0,0 +1,159 @@
+//! Observers that print events to the process's standard output and error streams.
+
+use crate::encoder::{EncodeError, Encoder, JsonEncoder, PrettyJsonEncoder};
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use serde::Serialize;
+use std::io::{self, IsTerminal};
+use std::sync::Arc;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+const STDOUT_COLOR: &str = "\x1b[32m";
+const STDERR_COLOR: &str = "\x1b[33m";
+const RESET_COLOR: &str = "\x1b[0m";
+
+/// An `Observer` that writes every event to standard output.
+///
+/// This is a convenience sink meant for quick debugging. Each event is encoded and written
+/// as one newline-terminated record, as compact JSON by default.
+pub struct StdoutObserver<T> {
+    encoder: Arc<dyn Encoder<T>>,
+    colored: bool,
+}
+
+impl<T: Serialize> StdoutObserver<T> {
+    /// Creates a new `StdoutObserver` that writes compact, uncolored JSON.
+    pub fn new() -> Self {
+        Self::with_encoder(Arc::new(JsonEncoder))
+    }
+
+    /// Sets whether events are pretty-printed as indented JSON.
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.encoder = json_encoder(pretty);
+        self
+    }
+}
+
+impl<T> StdoutObserver<T> {
+    /// Creates a new `StdoutObserver` that encodes events with `encoder`.
+    pub fn with_encoder(encoder: Arc<dyn Encoder<T>>) -> Self {
+        Self {
+            encoder,
+            colored: false,
+        }
+    }
+
+    /// Sets whether records are wrapped in ANSI colors.
+    ///
+    /// Colors are only emitted when standard output is a terminal, so redirecting the
+    /// output to a file or pipe never produces escape sequences.
+    pub fn colored(mut self, colored: bool) -> Self {
+        self.colored = colored;
+        self
+    }
+}
+
+impl<T: Serialize> Default for StdoutObserver<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for StdoutObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        let color = (self.colored && std::io::stdout().is_terminal()).then_some(STDOUT_COLOR);
+        let record = render(self.encoder.as_ref(), data, color)?;
+        write_record(tokio::io::stdout(), &record).await?;
+        Ok(())
+    }
+}
+
+/// An `Observer` that writes every event to standard error.
+///
+/// Behaves exactly like [`StdoutObserver`] but targets standard error, which keeps
+/// debugging output separate from a program's regular output.
+pub struct StderrObserver<T> {
+    encoder: Arc<dyn Encoder<T>>,
+    colored: bool,
+}
+
+impl<T: Serialize> StderrObserver<T> {
+    /// Creates a new `StderrObserver` that writes compact, uncolored JSON.
+    pub fn new() -> Self {
+        Self::with_encoder(Arc::new(JsonEncoder))
+    }
+
+    /// Sets whether events are pretty-printed as indented JSON.
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.encoder = json_encoder(pretty);
+        self
+    }
+}
+
+impl<T> StderrObserver<T> {
+    /// Creates a new `StderrObserver` that encodes events with `encoder`.
+    pub fn with_encoder(encoder: Arc<dyn Encoder<T>>) -> Self {
+        Self {
+            encoder,
+            colored: false,
+        }
+    }
+
+    /// Sets whether records are wrapped in ANSI colors.
+    ///
+    /// Colors are only emitted when standard error is a terminal.
+    pub fn colored(mut self, colored: bool) -> Self {
+        self.colored = colored;
+        self
+    }
+}
+
+impl<T: Serialize> Default for StderrObserver<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for StderrObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        let color = (self.colored && std::io::stderr().is_terminal()).then_some(STDERR_COLOR);
+        let record = render(self.encoder.as_ref(), data, color)?;
+        write_record(tokio::io::stderr(), &record).await?;
+        Ok(())
+    }
+}
+
+fn json_encoder<T: Serialize>(pretty: bool) -> Arc<dyn Encoder<T>> {
+    if pretty {
+        Arc::new(PrettyJsonEncoder)
+    } else {
+        Arc::new(JsonEncoder)
+    }
+}
+
+/// Encodes `data` into a single newline-terminated record, optionally wrapped in `color`.
+fn render<T>(
+    encoder: &dyn Encoder<T>,
+    data: &T,
+    color: Option<&str>,
+) -> Result<Vec<u8>, EncodeError> {
+    let encoded = encoder.encode(data)?;
+    let mut record = Vec::with_capacity(encoded.len() + 16);
+    if let Some(color) = color {
+        record.extend_from_slice(color.as_bytes());
+        record.extend_from_slice(&encoded);
+        record.extend_from_slice(RESET_COLOR.as_bytes());
+    } else {
+        record.extend_from_slice(&encoded);
+    }
+    record.push(b'\n');
+    Ok(record)
+}
+
+/// Writes a whole record with a single `write_all` so concurrent records don't interleave.
+async fn write_record<W: AsyncWrite + Unpin>(mut writer: W, record: &[u8]) -> io::Result<()> {
+    writer.write_all(record).await?;
+    writer.flush().await
+}
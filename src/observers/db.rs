@@ -0,0 +1,49 @@
+//! An observer that persists events to Postgres through `sqlx`.
+
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use sqlx::PgPool;
+
+/// The user-supplied function that writes one event using the pool.
+type InsertFn<T> =
+    Box<dyn for<'a> Fn(&'a T, &'a PgPool) -> BoxFuture<'a, Result<(), sqlx::Error>> + Send + Sync>;
+
+/// An `Observer` that persists every event by running a user-supplied insert function.
+///
+/// The function receives the event and the pool and controls the exact query and parameter
+/// binding. A failed insert is returned as an `ObserverError` and doesn't affect sibling
+/// observers.
+///
+/// Every in-flight `update` holds one pool connection for the duration of its query, so
+/// the pool's maximum size bounds how much database pressure a burst of notifications can
+/// create; size it according to the expected notify rate.
+pub struct SqlObserver<T> {
+    pool: PgPool,
+    insert: InsertFn<T>,
+}
+
+impl<T> SqlObserver<T> {
+    /// Creates a new `SqlObserver` that calls `insert` for every event.
+    pub fn new<F>(pool: PgPool, insert: F) -> Self
+    where
+        F: for<'a> Fn(&'a T, &'a PgPool) -> BoxFuture<'a, Result<(), sqlx::Error>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            pool,
+            insert: Box::new(insert),
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for SqlObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        (self.insert)(data, &self.pool)
+            .await
+            .map_err(ObserverError::other)
+    }
+}
@@ -0,0 +1,78 @@
+//! A decorator that collapses bursts of events into a single delivery.
+
+use crate::observers::{BackgroundErrors, update_in_background};
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+
+/// An `Observer` decorator that only notifies the inner observer once events stop arriving.
+///
+/// Every event restarts a quiet period of `delay`. Only when the quiet period elapses
+/// without a newer event is the most recent event (trailing edge) delivered to the inner
+/// observer; all earlier events of the burst are discarded. `update` itself never waits
+/// for the inner observer.
+///
+/// The timer runs on a background Tokio task owned by the observer. When the observer is
+/// dropped, a pending event is still delivered before the task exits. Errors and panics of
+/// the inner observer are logged there, or passed to the handler set with
+/// [`DebounceObserver::with_error_handler`].
+pub struct DebounceObserver<T> {
+    sender: watch::Sender<Option<T>>,
+    errors: BackgroundErrors,
+}
+
+impl<T: Clone + Send + Sync + 'static> DebounceObserver<T> {
+    /// Creates a new `DebounceObserver` delivering to `inner` after `delay` of quiet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside the context of a Tokio runtime.
+    pub fn new(inner: Arc<dyn Observer<T>>, delay: Duration) -> Self {
+        let (sender, receiver) = watch::channel(None);
+        let errors = BackgroundErrors::default();
+        tokio::spawn(run(inner, delay, receiver, errors.clone()));
+        Self { sender, errors }
+    }
+}
+
+impl<T> DebounceObserver<T> {
+    /// Sets a handler receiving the errors of the inner observer, a panic as
+    /// [`ObserverError::Panicked`]. Without one, they are only logged.
+    pub fn with_error_handler(
+        self,
+        handler: impl Fn(&ObserverError) + Send + Sync + 'static,
+    ) -> Self {
+        self.errors.set(handler);
+        self
+    }
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync> Observer<T> for DebounceObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        self.sender.send_replace(Some(data.clone()));
+        Ok(())
+    }
+}
+
+/// The background task waiting for each burst to settle before delivering its last event.
+async fn run<T: Clone + Send + Sync>(
+    inner: Arc<dyn Observer<T>>,
+    delay: Duration,
+    mut receiver: watch::Receiver<Option<T>>,
+    errors: BackgroundErrors,
+) {
+    // Each iteration handles one burst, starting with its first event.
+    while receiver.changed().await.is_ok() {
+        // A newer event within the quiet period restarts the timer; the burst is over once
+        // the quiet period elapses or the observer is dropped.
+        while let Ok(Ok(())) = tokio::time::timeout(delay, receiver.changed()).await {}
+
+        let latest = receiver.borrow_and_update().clone();
+        if let Some(event) = latest {
+            update_in_background(&*inner, &event, "Debounced", &errors).await;
+        }
+    }
+}
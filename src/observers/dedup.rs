@@ -0,0 +1,60 @@
+//! A decorator that skips consecutive duplicate events.
+
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use std::sync::Arc;
+#[cfg(feature = "logging")]
+use tracing::debug;
+
+/// An `Observer` decorator that drops an event when it repeats the previous one.
+///
+/// Each event is reduced to a key, by default the event itself, and the inner observer
+/// is only notified when that key differs from the key of the last delivered event. Use
+/// [`DedupObserver::with_key`] to compare a cheaper or more meaningful key, such as an
+/// id field, instead of the full event.
+///
+/// Only events the inner observer handled successfully count as delivered, so an event
+/// whose delivery failed is passed on again when it is repeated, e.g. by a retry.
+pub struct DedupObserver<T, K = T> {
+    inner: Arc<dyn Observer<T>>,
+    key_fn: Box<dyn Fn(&T) -> K + Send + Sync>,
+    last_key: Mutex<Option<K>>,
+}
+
+impl<T: PartialEq + Clone + 'static> DedupObserver<T> {
+    /// Creates a new `DedupObserver` comparing whole events for equality.
+    pub fn new(inner: Arc<dyn Observer<T>>) -> Self {
+        Self::with_key(inner, T::clone)
+    }
+}
+
+impl<T, K: PartialEq> DedupObserver<T, K> {
+    /// Creates a new `DedupObserver` comparing the keys produced by `key_fn`.
+    pub fn with_key(
+        inner: Arc<dyn Observer<T>>,
+        key_fn: impl Fn(&T) -> K + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            inner,
+            key_fn: Box::new(key_fn),
+            last_key: Mutex::new(None),
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync, K: PartialEq + Send> Observer<T> for DedupObserver<T, K> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        let key = (self.key_fn)(data);
+        if self.last_key.lock().as_ref() == Some(&key) {
+            #[cfg(feature = "logging")]
+            debug!("Deduplicating observer skipped a repeated event.");
+            return Ok(());
+        }
+
+        self.inner.update(data).await?;
+        *self.last_key.lock() = Some(key);
+        Ok(())
+    }
+}
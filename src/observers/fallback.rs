@@ -0,0 +1,43 @@
+//! An observer that falls back to a backup sink when the primary one fails.
+
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use std::sync::Arc;
+#[cfg(feature = "logging")]
+use tracing::warn;
+
+/// An `Observer` that delivers each event to a primary observer and, only if that fails,
+/// to a backup.
+///
+/// This suits "best-effort remote, guaranteed local" setups, e.g. a webhook as the primary
+/// and a local file as the backup. The primary's error is logged and replaced by the
+/// backup's result, so the `Subject` only sees a failure when both observers failed. A
+/// panicking primary is not caught here and is reported by the `Subject` as usual.
+pub struct FallbackObserver<T> {
+    primary: Arc<dyn Observer<T>>,
+    backup: Arc<dyn Observer<T>>,
+}
+
+impl<T> FallbackObserver<T> {
+    /// Creates a new `FallbackObserver` delivering to `primary`, and to `backup` whenever
+    /// `primary` returns an error.
+    pub const fn new(primary: Arc<dyn Observer<T>>, backup: Arc<dyn Observer<T>>) -> Self {
+        Self { primary, backup }
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for FallbackObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        match self.primary.update(data).await {
+            Ok(()) => Ok(()),
+            Err(error) => {
+                #[cfg(feature = "logging")]
+                warn!("Primary observer failed, falling back to backup: {}", error);
+                #[cfg(not(feature = "logging"))]
+                let _ = error;
+                self.backup.update(data).await
+            }
+        }
+    }
+}
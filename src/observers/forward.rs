@@ -0,0 +1,95 @@
+//! An observer that re-broadcasts events into another `Subject`.
+
+use crate::{Observer, ObserverError, ObserverHandle, Subject, SubjectInner};
+use async_trait::async_trait;
+use std::sync::{Arc, Weak};
+#[cfg(feature = "logging")]
+use tracing::warn;
+
+/// An `Observer` that forwards every event to a downstream `Subject`.
+///
+/// Attaching a `ForwardingObserver` to one subject re-broadcasts everything it receives to
+/// all observers of the downstream subject, which makes it possible to build hierarchical
+/// fan-out trees, e.g. several regional subjects feeding one global subject.
+///
+/// # Cycles
+///
+/// Forwarding must form a tree. If a subject forwards into itself, directly or through a
+/// chain of forwarding observers, every `notify` recurses without end. Direct
+/// self-forwarding is rejected by [`Subject::forward_to`], but transitive cycles can't be
+/// detected and must be avoided by the caller.
+pub struct ForwardingObserver<T> {
+    downstream: Subject<T>,
+}
+
+impl<T> ForwardingObserver<T> {
+    /// Creates a new `ForwardingObserver` that re-notifies `downstream` of every event.
+    pub const fn new(downstream: Subject<T>) -> Self {
+        Self { downstream }
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync + 'static> Observer<T> for ForwardingObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        self.downstream.notify(data).await;
+        Ok(())
+    }
+}
+
+impl<T: Send + Sync + 'static> Subject<T> {
+    /// Forwards every event notified on this `Subject` to `downstream`.
+    ///
+    /// This attaches a [`ForwardingObserver`] and returns its handle, or `None` without
+    /// attaching anything if `downstream` is this very subject, which would recurse forever.
+    pub async fn forward_to(&self, downstream: &Self) -> Option<ObserverHandle<T>> {
+        if Arc::ptr_eq(&self.inner, &downstream.inner) {
+            #[cfg(feature = "logging")]
+            warn!("Refusing to forward a subject into itself.");
+            return None;
+        }
+        let observer = Arc::new(ForwardingObserver::new(downstream.clone()));
+        Some(self.attach(observer).await)
+    }
+}
+
+/// An internal observer that notifies a derived view subject of every mapped event.
+///
+/// It only holds the view weakly: the view owns this observer's handle instead, so
+/// dropping the last clone of the view detaches the observer from the source.
+struct MappingObserver<T, U> {
+    view: Weak<SubjectInner<U>>,
+    map: Box<dyn Fn(&T) -> U + Send + Sync>,
+}
+
+#[async_trait]
+impl<T: Send + Sync, U: Send + Sync + 'static> Observer<T> for MappingObserver<T, U> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        if let Some(inner) = self.view.upgrade() {
+            Subject { inner }.notify(&(self.map)(data)).await;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Send + Sync + 'static> Subject<T> {
+    /// Creates a view `Subject` whose observers receive every event of this `Subject`,
+    /// projected through `map`.
+    ///
+    /// Data only flows one way: notifying the view reaches the view's observers but never
+    /// this `Subject`. The view keeps its connection to this `Subject` alive and severs it
+    /// once its last clone is dropped.
+    pub async fn map_into<U: Send + Sync + 'static>(
+        &self,
+        map: impl Fn(&T) -> U + Send + Sync + 'static,
+    ) -> Subject<U> {
+        let view = Subject::new();
+        let observer = MappingObserver {
+            view: Arc::downgrade(&view.inner),
+            map: Box::new(map),
+        };
+        let handle = self.attach(Arc::new(observer)).await;
+        view.inner.upstream.lock().push(Box::new(handle));
+        view
+    }
+}
@@ -0,0 +1,70 @@
+//! An observer feeding a client-streaming gRPC call.
+
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use futures::Stream;
+use tokio::sync::mpsc;
+
+/// An `Observer` that converts every event into a protobuf message and sends it into the
+/// request stream of a client-streaming gRPC call.
+///
+/// The observer holds the send half of a bounded channel; the receive half, turned into a
+/// `Stream`, is passed as the request of the `tonic` client call. `tonic` accepts any
+/// `Stream` of messages as a streaming request, so no `tonic` types are involved here:
+///
+/// ```ignore
+/// let (observer, messages) = GrpcObserver::channel(64, |event: &Event| EventMsg::from(event));
+/// let _handle = subject.attach(Arc::new(observer)).await;
+/// tokio::spawn(async move { client.publish(messages).await });
+/// ```
+///
+/// `update` waits for room in the channel, so a slow stream applies backpressure to the
+/// notification. Once the stream has ended, e.g. because the call failed, `update` returns
+/// `ObserverError::ChannelClosed`.
+pub struct GrpcObserver<T, M> {
+    sender: mpsc::Sender<M>,
+    convert: Box<dyn Fn(&T) -> M + Send + Sync>,
+}
+
+impl<T, M> GrpcObserver<T, M> {
+    /// Creates a new `GrpcObserver` sending messages built by `convert` through `sender`.
+    pub fn new<F>(sender: mpsc::Sender<M>, convert: F) -> Self
+    where
+        F: Fn(&T) -> M + Send + Sync + 'static,
+    {
+        Self {
+            sender,
+            convert: Box::new(convert),
+        }
+    }
+}
+
+impl<T, M: Send + 'static> GrpcObserver<T, M> {
+    /// Creates a new `GrpcObserver` together with the request stream for the gRPC call,
+    /// buffering at most `capacity` messages.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn channel<F>(capacity: usize, convert: F) -> (Self, impl Stream<Item = M> + Send + 'static)
+    where
+        F: Fn(&T) -> M + Send + Sync + 'static,
+    {
+        let (sender, receiver) = mpsc::channel(capacity);
+        let messages = futures::stream::unfold(receiver, |mut receiver| async move {
+            let message = receiver.recv().await?;
+            Some((message, receiver))
+        });
+        (Self::new(sender, convert), messages)
+    }
+}
+
+#[async_trait]
+impl<T: Sync, M: Send> Observer<T> for GrpcObserver<T, M> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        self.sender
+            .send((self.convert)(data))
+            .await
+            .map_err(|_| ObserverError::ChannelClosed)
+    }
+}
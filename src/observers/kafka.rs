@@ -0,0 +1,88 @@
+//! An observer that produces events to a Kafka topic.
+
+use crate::encoder::{Encoder, JsonEncoder};
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The default upper bound on how long a single send may take.
+const DEFAULT_SEND_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The user-supplied function deriving a record key from an event.
+type KeyFn<T> = Box<dyn Fn(&T) -> String + Send + Sync>;
+
+/// An `Observer` that produces every event as a record on a Kafka topic.
+///
+/// Events are encoded as compact JSON by default, and `update` waits for the broker to
+/// acknowledge delivery. An optional key function assigns each record a key, which Kafka
+/// uses to pick the partition. Every send is bounded by a timeout, so a stalled broker
+/// can't hold up the whole `notify` fan-out; failed or timed-out sends are returned as errors.
+pub struct KafkaObserver<T> {
+    producer: FutureProducer,
+    topic: String,
+    encoder: Arc<dyn Encoder<T>>,
+    key_fn: Option<KeyFn<T>>,
+    send_timeout: Duration,
+}
+
+impl<T: Serialize> KafkaObserver<T> {
+    /// Creates a new `KafkaObserver` producing JSON-encoded events to `topic`.
+    pub fn new(producer: FutureProducer, topic: impl Into<String>) -> Self {
+        Self::with_encoder(producer, topic, Arc::new(JsonEncoder))
+    }
+}
+
+impl<T> KafkaObserver<T> {
+    /// Creates a new `KafkaObserver` producing events encoded with `encoder`.
+    pub fn with_encoder(
+        producer: FutureProducer,
+        topic: impl Into<String>,
+        encoder: Arc<dyn Encoder<T>>,
+    ) -> Self {
+        Self {
+            producer,
+            topic: topic.into(),
+            encoder,
+            key_fn: None,
+            send_timeout: DEFAULT_SEND_TIMEOUT,
+        }
+    }
+
+    /// Sets a function deriving each record's key, and therefore its partition, from the event.
+    pub fn with_key(mut self, key_fn: impl Fn(&T) -> String + Send + Sync + 'static) -> Self {
+        self.key_fn = Some(Box::new(key_fn));
+        self
+    }
+
+    /// Sets how long a single send may take before it is abandoned. Defaults to 5 seconds.
+    pub const fn with_send_timeout(mut self, send_timeout: Duration) -> Self {
+        self.send_timeout = send_timeout;
+        self
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for KafkaObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        let payload = self.encoder.encode(data)?;
+
+        let key = self.key_fn.as_ref().map(|key_fn| key_fn(data));
+        let mut record = FutureRecord::to(&self.topic).payload(&payload);
+        if let Some(key) = &key {
+            record = record.key(key.as_str());
+        }
+
+        let send = self.producer.send(record, self.send_timeout);
+        match tokio::time::timeout(self.send_timeout, send).await {
+            Ok(Ok(_)) => Ok(()),
+            Ok(Err((error, _message))) => Err(ObserverError::other(error)),
+            Err(_) => Err(ObserverError::other(format!(
+                "timed out producing event to Kafka topic {} after {:?}",
+                self.topic, self.send_timeout
+            ))),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! A decorator that measures how long the inner observer takes to handle each event.
+
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// An `Observer` decorator that times every `update` of the inner observer.
+///
+/// After each update the elapsed time is passed to a user-supplied callback, which can feed
+/// any metrics backend without modifying the observer being measured.
+pub struct LatencyObserver<T> {
+    inner: Arc<dyn Observer<T>>,
+    record: Box<dyn Fn(Duration) + Send + Sync>,
+}
+
+impl<T> LatencyObserver<T> {
+    /// Creates a new `LatencyObserver` reporting the duration of each `inner` update to
+    /// `record`.
+    pub fn new(
+        inner: Arc<dyn Observer<T>>,
+        record: impl Fn(Duration) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            inner,
+            record: Box::new(record),
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for LatencyObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        let started = Instant::now();
+        let result = self.inner.update(data).await;
+        (self.record)(started.elapsed());
+        result
+    }
+}
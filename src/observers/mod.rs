@@ -0,0 +1,126 @@
+//! Ready-made `Observer` implementations.
+//!
+//! Observers that depend on a specific runtime or third-party crate are gated behind
+//! cargo features so the core crate stays runtime-agnostic.
+
+#[cfg(feature = "tokio")]
+use crate::{Observer, ObserverError};
+#[cfg(feature = "tokio")]
+use futures::FutureExt;
+#[cfg(feature = "tokio")]
+use parking_lot::Mutex;
+#[cfg(feature = "tokio")]
+use std::panic::AssertUnwindSafe;
+#[cfg(feature = "tokio")]
+use std::sync::Arc;
+#[cfg(all(feature = "tokio", feature = "logging"))]
+use tracing::error;
+
+#[cfg(feature = "tokio")]
+pub mod broadcast;
+pub mod buffer;
+#[cfg(feature = "tokio")]
+pub mod buffered;
+pub mod circuit;
+#[cfg(feature = "tokio")]
+pub mod coalesce;
+#[cfg(feature = "console")]
+pub mod console;
+#[cfg(feature = "sqlx")]
+pub mod db;
+#[cfg(feature = "tokio")]
+pub mod debounce;
+pub mod dedup;
+pub mod fallback;
+pub mod forward;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+#[cfg(feature = "kafka")]
+pub mod kafka;
+pub mod latency;
+#[cfg(feature = "nats")]
+pub mod nats;
+pub mod null;
+pub mod once;
+#[cfg(feature = "otel")]
+pub mod otel;
+pub mod partition;
+#[cfg(feature = "prometheus")]
+pub mod prometheus;
+#[cfg(any(feature = "webhook", feature = "telegram"))]
+mod rate_limit;
+#[cfg(feature = "redis")]
+pub mod redis;
+pub mod router;
+pub mod sample;
+#[cfg(feature = "aws")]
+pub mod sqs;
+#[cfg(feature = "syslog")]
+pub mod syslog;
+pub mod tee;
+#[cfg(feature = "telegram")]
+pub mod telegram;
+#[cfg(feature = "tokio")]
+pub mod throttle;
+pub mod variant;
+#[cfg(feature = "tokio")]
+pub mod watch;
+#[cfg(feature = "webhook")]
+pub mod webhook;
+
+/// The handler set on a decorator to receive its background task's failures.
+#[cfg(feature = "tokio")]
+type ErrorHandler = Arc<dyn Fn(&ObserverError) + Send + Sync>;
+
+/// The error handler slot shared between a decorator and its background task, so the
+/// handler can still be set after the task has started.
+#[cfg(feature = "tokio")]
+#[derive(Clone, Default)]
+pub(crate) struct BackgroundErrors(Arc<Mutex<Option<ErrorHandler>>>);
+
+#[cfg(feature = "tokio")]
+impl BackgroundErrors {
+    /// Sets the handler, replacing any previous one.
+    pub(crate) fn set(&self, handler: impl Fn(&ObserverError) + Send + Sync + 'static) {
+        *self.0.lock() = Some(Arc::new(handler));
+    }
+
+    /// Passes a failure to the handler, if one is set.
+    fn report(&self, error: &ObserverError) {
+        let handler = self.0.lock().clone();
+        if let Some(handler) = handler {
+            handler(error);
+        }
+    }
+}
+
+/// Delivers an event to `inner` from a decorator's background task.
+///
+/// There is no caller to return a failure to, so errors and panics are logged, naming the
+/// decorator, e.g. `"Buffered"`, and passed to the decorator's error handler, a panic as
+/// [`ObserverError::Panicked`]. A panic is caught so that it can't end the task.
+#[cfg(feature = "tokio")]
+pub(crate) async fn update_in_background<T: Sync>(
+    inner: &dyn Observer<T>,
+    event: &T,
+    name: &str,
+    errors: &BackgroundErrors,
+) {
+    let update = AssertUnwindSafe(inner.update(event)).catch_unwind();
+    let error = match update.await {
+        Ok(Ok(())) => return,
+        Ok(Err(error)) => {
+            #[cfg(feature = "logging")]
+            error!("{} observer failed to handle event: {}", name, error);
+            error
+        }
+        Err(_) => {
+            #[cfg(feature = "logging")]
+            error!("{} observer panicked during update.", name);
+            ObserverError::Panicked
+        }
+    };
+    #[cfg(not(feature = "logging"))]
+    let _ = name;
+    errors.report(&error);
+}
@@ -0,0 +1,90 @@
+//! An observer that publishes events to a NATS subject.
+
+use crate::encoder::{Encoder, JsonEncoder};
+use crate::{Observer, ObserverError};
+use async_nats::{Client, HeaderMap};
+use async_trait::async_trait;
+use serde::Serialize;
+use std::sync::Arc;
+
+/// An `Observer` that publishes every event to a NATS subject.
+///
+/// Events are encoded as compact JSON by default. A reply subject can be attached for
+/// request/response flows, and a fixed header map, e.g. carrying tracing context, is sent
+/// with every message. Publish errors are returned and never abort the fan-out.
+pub struct NatsObserver<T> {
+    client: Client,
+    subject: String,
+    encoder: Arc<dyn Encoder<T>>,
+    reply: Option<String>,
+    headers: Option<HeaderMap>,
+}
+
+impl<T: Serialize> NatsObserver<T> {
+    /// Creates a new `NatsObserver` publishing JSON-encoded events to `subject`.
+    pub fn new(client: Client, subject: impl Into<String>) -> Self {
+        Self::with_encoder(client, subject, Arc::new(JsonEncoder))
+    }
+}
+
+impl<T> NatsObserver<T> {
+    /// Creates a new `NatsObserver` publishing events encoded with `encoder`.
+    pub fn with_encoder(
+        client: Client,
+        subject: impl Into<String>,
+        encoder: Arc<dyn Encoder<T>>,
+    ) -> Self {
+        Self {
+            client,
+            subject: subject.into(),
+            encoder,
+            reply: None,
+            headers: None,
+        }
+    }
+
+    /// Sets the reply subject sent with every message.
+    pub fn with_reply(mut self, reply: impl Into<String>) -> Self {
+        self.reply = Some(reply.into());
+        self
+    }
+
+    /// Sets the headers sent with every message.
+    pub fn with_headers(mut self, headers: HeaderMap) -> Self {
+        self.headers = Some(headers);
+        self
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for NatsObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        let payload = self.encoder.encode(data)?.into();
+
+        let subject = self.subject.clone();
+        let result = match (&self.reply, &self.headers) {
+            (None, None) => self.client.publish(subject, payload).await,
+            (Some(reply), None) => {
+                self.client
+                    .publish_with_reply(subject, reply.clone(), payload)
+                    .await
+            }
+            (None, Some(headers)) => {
+                self.client
+                    .publish_with_headers(subject, headers.clone(), payload)
+                    .await
+            }
+            (Some(reply), Some(headers)) => {
+                self.client
+                    .publish_with_reply_and_headers(
+                        subject,
+                        reply.clone(),
+                        headers.clone(),
+                        payload,
+                    )
+                    .await
+            }
+        };
+        result.map_err(ObserverError::other)
+    }
+}
@@ -0,0 +1,36 @@
+//! An observer that ignores every event.
+
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use std::marker::PhantomData;
+
+/// An `Observer` whose `update` does nothing.
+///
+/// Useful as a placeholder when wiring is assembled conditionally, or to exercise a
+/// `Subject` in tests without any side effects.
+pub struct NullObserver<T> {
+    // `fn() -> T` keeps the observer `Send` and `Sync` regardless of `T`.
+    _event: PhantomData<fn() -> T>,
+}
+
+impl<T> NullObserver<T> {
+    /// Creates a new `NullObserver`.
+    pub const fn new() -> Self {
+        Self {
+            _event: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for NullObserver<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl<T: Sync> Observer<T> for NullObserver<T> {
+    async fn update(&self, _data: &T) -> Result<(), ObserverError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+//! An observer that handles a single event and then detaches itself.
+
+use crate::{Observer, ObserverError, ObserverHandle, Subject};
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// An `Observer` decorator that forwards only the first event to the inner observer and
+/// then detaches itself from its `Subject`.
+///
+/// The observer owns its own `ObserverHandle`, handed to it with [`OnceObserver::arm`],
+/// and drops it after the first event, which detaches it like dropping any handle. Use
+/// [`Subject::attach_self_removing`] to attach and arm it in one step. Later events that
+/// race with the detachment are ignored and return `Ok`.
+pub struct OnceObserver<T> {
+    inner: Arc<dyn Observer<T>>,
+    fired: AtomicBool,
+    handle: Mutex<Option<ObserverHandle<T>>>,
+}
+
+impl<T> OnceObserver<T> {
+    /// Creates a new `OnceObserver` forwarding the first event to `inner`.
+    pub fn new(inner: Arc<dyn Observer<T>>) -> Self {
+        Self {
+            inner,
+            fired: AtomicBool::new(false),
+            handle: Mutex::new(None),
+        }
+    }
+
+    /// Hands the observer the handle it was attached with, so it can detach itself.
+    ///
+    /// If the observer already fired, e.g. on an event replayed from the history while it
+    /// was being attached, the handle is dropped right away.
+    pub fn arm(&self, handle: ObserverHandle<T>) {
+        let mut slot = self.handle.lock();
+        if self.fired.load(Ordering::SeqCst) {
+            drop(slot);
+            drop(handle);
+        } else {
+            *slot = Some(handle);
+        }
+    }
+
+    /// Returns `true` once the observer has received its event.
+    pub fn has_fired(&self) -> bool {
+        self.fired.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for OnceObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        if self.fired.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+        let result = self.inner.update(data).await;
+        // Dropping the handle detaches the observer. It is taken out of the lock first, as
+        // detaching may run arbitrary code.
+        let handle = self.handle.lock().take();
+        drop(handle);
+        result
+    }
+}
+
+impl<T: Send + Sync + 'static> Subject<T> {
+    /// Attaches `inner` wrapped in a [`OnceObserver`], so it receives only the first event
+    /// and is then detached automatically. Returns the observer's ID.
+    ///
+    /// No handle is returned, since the observer owns it; it can still be removed before it
+    /// fires with [`Subject::take`] or any of the bulk removal methods.
+    pub async fn attach_self_removing(&self, inner: Arc<dyn Observer<T>>) -> u64 {
+        let observer = Arc::new(OnceObserver::new(inner));
+        let handle = self
+            .attach(Arc::clone(&observer) as Arc<dyn Observer<T>>)
+            .await;
+        let id = handle.id();
+        observer.arm(handle);
+        id
+    }
+}
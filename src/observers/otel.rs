@@ -0,0 +1,46 @@
+//! An observer that records events on the current OpenTelemetry span.
+
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use opentelemetry::trace::TraceContextExt;
+use opentelemetry::{Context, KeyValue};
+use std::borrow::Cow;
+
+/// The user-supplied function extracting the span event attributes from an event.
+type AttributesFn<T> = Box<dyn Fn(&T) -> Vec<KeyValue> + Send + Sync>;
+
+/// An `Observer` that adds a span event to the current OpenTelemetry span for every event.
+///
+/// The span event carries a fixed name and the attributes extracted from the event by a
+/// user-supplied function, which correlates domain events with the request traces they
+/// happen in. Recording is purely in-process and never awaits. The span is taken from the
+/// OpenTelemetry context that is current while `notify` runs the observer; when no span is
+/// active the event is silently discarded by OpenTelemetry.
+pub struct OtelObserver<T> {
+    name: Cow<'static, str>,
+    attributes: AttributesFn<T>,
+}
+
+impl<T> OtelObserver<T> {
+    /// Creates a new `OtelObserver` recording span events called `name` with the
+    /// attributes produced by `attributes`.
+    pub fn new(
+        name: impl Into<Cow<'static, str>>,
+        attributes: impl Fn(&T) -> Vec<KeyValue> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            attributes: Box::new(attributes),
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for OtelObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        Context::current()
+            .span()
+            .add_event(self.name.clone(), (self.attributes)(data));
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+//! A decorator that shards events across a fixed set of observers by key.
+
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use std::sync::Arc;
+#[cfg(feature = "logging")]
+use tracing::trace;
+
+/// An `Observer` that delivers each event to one of several partitions, chosen by its key.
+///
+/// Every event is mapped to a `u64` key, typically a hash of an entity ID, and goes to the
+/// partition at `key % partitions.len()`, so all events of one key reach the same
+/// partition. Wrapping each partition in a `BufferedObserver` gives every shard its own
+/// queue and worker: events of one key are then handled in order while different shards
+/// run in parallel. Without partitions, events are dropped.
+pub struct PartitionObserver<T> {
+    partitions: Vec<Arc<dyn Observer<T>>>,
+    key_fn: Box<dyn Fn(&T) -> u64 + Send + Sync>,
+}
+
+impl<T> PartitionObserver<T> {
+    /// Creates a new `PartitionObserver` delivering each event to the partition selected
+    /// by its `key_fn` key.
+    pub fn new(
+        partitions: Vec<Arc<dyn Observer<T>>>,
+        key_fn: impl Fn(&T) -> u64 + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            partitions,
+            key_fn: Box::new(key_fn),
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for PartitionObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        let key = (self.key_fn)(data);
+        let partition = u64::try_from(self.partitions.len())
+            .ok()
+            .and_then(|count| key.checked_rem(count))
+            .and_then(|index| usize::try_from(index).ok())
+            .and_then(|index| self.partitions.get(index));
+        match partition {
+            Some(observer) => observer.update(data).await,
+            None => {
+                #[cfg(feature = "logging")]
+                trace!("Partition observer has no partitions, event dropped.");
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! An observer that records events into Prometheus metrics.
+
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use prometheus::{Histogram, IntCounterVec};
+
+/// The user-supplied function extracting the counter label values from an event.
+type LabelsFn<T> = Box<dyn Fn(&T) -> Vec<String> + Send + Sync>;
+
+enum Metric<T> {
+    Histogram {
+        histogram: Histogram,
+        value: Box<dyn Fn(&T) -> f64 + Send + Sync>,
+    },
+    Counter {
+        counter: IntCounterVec,
+        labels: LabelsFn<T>,
+    },
+}
+
+/// An `Observer` that bumps a Prometheus metric for every event.
+///
+/// A histogram observer records the value extracted from each event, while a counter
+/// observer increments the series selected by the label values extracted from each event.
+/// Recording is purely in-process and never awaits, so the observer adds no IO to the
+/// fan-out; the metrics are exposed by whatever registry they were registered with.
+pub struct PrometheusObserver<T> {
+    metric: Metric<T>,
+}
+
+impl<T> PrometheusObserver<T> {
+    /// Creates a new `PrometheusObserver` that observes `value(event)` in `histogram`.
+    pub fn histogram(
+        histogram: Histogram,
+        value: impl Fn(&T) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            metric: Metric::Histogram {
+                histogram,
+                value: Box::new(value),
+            },
+        }
+    }
+
+    /// Creates a new `PrometheusObserver` that increments the `counter` series labelled
+    /// with `labels(event)`.
+    ///
+    /// The extracted label values must match the counter's label names in number and
+    /// order; for events that don't, `update` returns an error.
+    pub fn counter(
+        counter: IntCounterVec,
+        labels: impl Fn(&T) -> Vec<String> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            metric: Metric::Counter {
+                counter,
+                labels: Box::new(labels),
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for PrometheusObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        match &self.metric {
+            Metric::Histogram { histogram, value } => histogram.observe(value(data)),
+            Metric::Counter { counter, labels } => {
+                let labels = labels(data);
+                let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+                counter
+                    .get_metric_with_label_values(&labels)
+                    .map_err(ObserverError::other)?
+                    .inc();
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+//! Retrying HTTP requests rejected with `429 Too Many Requests`, shared by the chat
+//! observers.
+
+use reqwest::{Response, StatusCode};
+use std::future::Future;
+use std::time::Duration;
+#[cfg(feature = "logging")]
+use tracing::warn;
+
+/// How many times a rate-limited request is retried before the event is given up on.
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+/// The wait used when a rate-limited response doesn't say how long to back off.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+/// The longest back-off honored, so a misbehaving server can't stall `notify`.
+pub(crate) const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Sends the request issued by `send`, retrying up to three times while the server answers
+/// `429 Too Many Requests`.
+///
+/// `retry_after` reads the back-off from a rate-limited response, falling back to one
+/// second when it returns `None`; back-offs are capped at a minute. `service` names the
+/// server in the log. Any other error status of the final response is returned as an error.
+pub(crate) async fn send_with_retry<S, R>(
+    send: impl Fn() -> S,
+    retry_after: impl Fn(Response) -> R,
+    service: &str,
+) -> Result<(), reqwest::Error>
+where
+    S: Future<Output = Result<Response, reqwest::Error>>,
+    R: Future<Output = Option<Duration>>,
+{
+    let mut attempt = 0;
+    loop {
+        let response = send().await?;
+        if response.status() != StatusCode::TOO_MANY_REQUESTS || attempt >= MAX_RATE_LIMIT_RETRIES {
+            return response.error_for_status().map(drop);
+        }
+
+        let delay = retry_after(response)
+            .await
+            .map_or(DEFAULT_RETRY_AFTER, |delay| delay.min(MAX_RETRY_AFTER));
+        #[cfg(feature = "logging")]
+        warn!("{} rate limited, retrying in {:?}.", service, delay);
+        #[cfg(not(feature = "logging"))]
+        let _ = service;
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
@@ -0,0 +1,55 @@
+//! An observer that publishes events to a Redis pub/sub channel.
+
+use crate::encoder::{Encoder, JsonEncoder};
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use redis::aio::ConnectionManager;
+use redis::{AsyncCommands, RedisResult};
+use serde::Serialize;
+use std::sync::Arc;
+
+/// An `Observer` that issues `PUBLISH channel payload` for every event.
+///
+/// Events are encoded as compact JSON by default. The observer holds a
+/// `redis::aio::ConnectionManager`, which transparently re-establishes a dropped connection
+/// on the next command: a publish that fails because the connection went away returns an
+/// error, and the following `update` publishes over a fresh connection.
+pub struct RedisObserver<T> {
+    connection: ConnectionManager,
+    channel: String,
+    encoder: Arc<dyn Encoder<T>>,
+}
+
+impl<T: Serialize> RedisObserver<T> {
+    /// Creates a new `RedisObserver` publishing JSON-encoded events to `channel`.
+    pub fn new(connection: ConnectionManager, channel: impl Into<String>) -> Self {
+        Self::with_encoder(connection, channel, Arc::new(JsonEncoder))
+    }
+}
+
+impl<T> RedisObserver<T> {
+    /// Creates a new `RedisObserver` publishing events encoded with `encoder`.
+    pub fn with_encoder(
+        connection: ConnectionManager,
+        channel: impl Into<String>,
+        encoder: Arc<dyn Encoder<T>>,
+    ) -> Self {
+        Self {
+            connection,
+            channel: channel.into(),
+            encoder,
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for RedisObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        let payload = self.encoder.encode(data)?;
+
+        // Cloning a `ConnectionManager` is cheap and shares the underlying connection.
+        let mut connection = self.connection.clone();
+        let result: RedisResult<()> = connection.publish(&self.channel, payload).await;
+        result.map_err(ObserverError::other)
+    }
+}
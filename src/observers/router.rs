@@ -0,0 +1,57 @@
+//! A decorator that routes each event to one observer based on its content.
+
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Arc;
+#[cfg(feature = "logging")]
+use tracing::trace;
+
+/// An `Observer` that dispatches each event to the observer registered for its key.
+///
+/// Every event is classified once into a key, and only the route registered for that key
+/// is notified. Events whose key has no route go to the fallback observer set with
+/// [`RouterObserver::with_fallback`], or are dropped if there is none. This replaces
+/// attaching several filtering observers with mutually exclusive predicates, each of which
+/// would be evaluated for every event.
+pub struct RouterObserver<T, K> {
+    classify: Box<dyn Fn(&T) -> K + Send + Sync>,
+    routes: HashMap<K, Arc<dyn Observer<T>>>,
+    fallback: Option<Arc<dyn Observer<T>>>,
+}
+
+impl<T, K: Eq + Hash> RouterObserver<T, K> {
+    /// Creates a new `RouterObserver` sending each event to the route for its `classify` key.
+    pub fn new(
+        classify: impl Fn(&T) -> K + Send + Sync + 'static,
+        routes: HashMap<K, Arc<dyn Observer<T>>>,
+    ) -> Self {
+        Self {
+            classify: Box::new(classify),
+            routes,
+            fallback: None,
+        }
+    }
+
+    /// Sets the observer receiving the events whose key has no route.
+    pub fn with_fallback(mut self, fallback: Arc<dyn Observer<T>>) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync, K: Eq + Hash + Send + Sync> Observer<T> for RouterObserver<T, K> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        let key = (self.classify)(data);
+        match self.routes.get(&key).or(self.fallback.as_ref()) {
+            Some(observer) => observer.update(data).await,
+            None => {
+                #[cfg(feature = "logging")]
+                trace!("Router observer has no route for event, event dropped.");
+                Ok(())
+            }
+        }
+    }
+}
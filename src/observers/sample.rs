@@ -0,0 +1,90 @@
+//! A decorator that forwards only a sample of the events.
+
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use std::cell::Cell;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+thread_local! {
+    // A xorshift generator per thread, seeded from the randomly keyed `RandomState`. It
+    // only needs to be fast and roughly uniform, not unpredictable.
+    static RNG: Cell<u64> = Cell::new(RandomState::new().build_hasher().finish() | 1);
+}
+
+/// Returns a uniformly distributed number in `[0, 1)`.
+fn next_unit() -> f64 {
+    RNG.with(|rng| {
+        let mut x = rng.get();
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        rng.set(x);
+        // The top 53 bits fill an `f64` mantissa exactly.
+        (x >> 11) as f64 / (1u64 << 53) as f64
+    })
+}
+
+/// How a [`SampleObserver`] picks the events it forwards.
+enum Sampling {
+    Probability(f64),
+    EveryNth { n: u64, counter: AtomicU64 },
+}
+
+/// An `Observer` decorator that forwards only a fraction of the events to the inner
+/// observer, e.g. to cut the cost of an expensive analytics sink.
+///
+/// Sampling is either probabilistic, see [`SampleObserver::probability`], or deterministic,
+/// forwarding every n-th event, see [`SampleObserver::every_nth`]. Decisions are made per
+/// event and per observer: two sampling observers on the same `Subject` don't pick the
+/// same events. Skipped events return `Ok`.
+pub struct SampleObserver<T> {
+    inner: Arc<dyn Observer<T>>,
+    sampling: Sampling,
+}
+
+impl<T> SampleObserver<T> {
+    /// Creates a new `SampleObserver` forwarding each event with probability `rate`.
+    ///
+    /// A `rate` of `1.0 / 100.0` forwards about one event in a hundred. Rates at or below
+    /// `0.0` forward nothing and rates at or above `1.0` forward everything. The random
+    /// numbers come from a fast thread-local generator.
+    pub const fn probability(inner: Arc<dyn Observer<T>>, rate: f64) -> Self {
+        Self {
+            inner,
+            sampling: Sampling::Probability(rate),
+        }
+    }
+
+    /// Creates a new `SampleObserver` forwarding every `n`-th event, starting with the
+    /// first one. An `n` of zero is treated as one, forwarding every event.
+    pub const fn every_nth(inner: Arc<dyn Observer<T>>, n: u64) -> Self {
+        Self {
+            inner,
+            sampling: Sampling::EveryNth {
+                n: if n == 0 { 1 } else { n },
+                counter: AtomicU64::new(0),
+            },
+        }
+    }
+
+    /// Decides whether the current event is forwarded.
+    fn sampled(&self) -> bool {
+        match &self.sampling {
+            Sampling::Probability(rate) => next_unit() < *rate,
+            Sampling::EveryNth { n, counter } => counter.fetch_add(1, Ordering::Relaxed) % n == 0,
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for SampleObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        if self.sampled() {
+            return self.inner.update(data).await;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+//! An observer that sends events to an Amazon SQS queue.
+
+use crate::encoder::{Encoder, JsonEncoder};
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use aws_sdk_sqs::Client;
+use aws_sdk_sqs::error::ProvideErrorMetadata;
+use aws_sdk_sqs::types::MessageAttributeValue;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+#[cfg(feature = "logging")]
+use tracing::warn;
+
+/// How many times a throttled send is retried before the event is given up on.
+const MAX_THROTTLE_RETRIES: u32 = 3;
+/// The back-off before the first retry, doubled for every further one.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+
+/// The user-supplied function deriving the FIFO message group of an event.
+type GroupIdFn<T> = Box<dyn Fn(&T) -> String + Send + Sync>;
+
+/// An `Observer` that sends every event as a message to an SQS queue.
+///
+/// Events are encoded as compact JSON by default; since SQS message bodies are text, a
+/// custom encoder must produce valid UTF-8. For FIFO queues, a group id function assigns
+/// each message its message group, and the queue should have content-based deduplication
+/// enabled. A fixed set of message attributes can be sent with every message.
+///
+/// Sends rejected because of throttling are retried with exponential back-off, up to three
+/// times. Other failures are returned as errors and never abort the fan-out.
+pub struct SqsObserver<T> {
+    client: Client,
+    queue_url: String,
+    encoder: Arc<dyn Encoder<T>>,
+    group_id_fn: Option<GroupIdFn<T>>,
+    attributes: Option<HashMap<String, MessageAttributeValue>>,
+}
+
+impl<T: Serialize> SqsObserver<T> {
+    /// Creates a new `SqsObserver` sending JSON-encoded events to the queue at `queue_url`.
+    pub fn new(client: Client, queue_url: impl Into<String>) -> Self {
+        Self::with_encoder(client, queue_url, Arc::new(JsonEncoder))
+    }
+}
+
+impl<T> SqsObserver<T> {
+    /// Creates a new `SqsObserver` sending events encoded with `encoder`.
+    pub fn with_encoder(
+        client: Client,
+        queue_url: impl Into<String>,
+        encoder: Arc<dyn Encoder<T>>,
+    ) -> Self {
+        Self {
+            client,
+            queue_url: queue_url.into(),
+            encoder,
+            group_id_fn: None,
+            attributes: None,
+        }
+    }
+
+    /// Sets a function deriving each message's group id from the event, for FIFO queues.
+    pub fn with_group_id(
+        mut self,
+        group_id_fn: impl Fn(&T) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.group_id_fn = Some(Box::new(group_id_fn));
+        self
+    }
+
+    /// Sets the message attributes sent with every message.
+    pub fn with_attributes(mut self, attributes: HashMap<String, MessageAttributeValue>) -> Self {
+        self.attributes = Some(attributes);
+        self
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for SqsObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        let body = String::from_utf8(self.encoder.encode(data)?).map_err(ObserverError::other)?;
+        let group_id = self
+            .group_id_fn
+            .as_ref()
+            .map(|group_id_fn| group_id_fn(data));
+
+        let mut attempt = 0;
+        loop {
+            let result = self
+                .client
+                .send_message()
+                .queue_url(&self.queue_url)
+                .message_body(&body)
+                .set_message_group_id(group_id.clone())
+                .set_message_attributes(self.attributes.clone())
+                .send()
+                .await;
+
+            match result {
+                Ok(_) => return Ok(()),
+                Err(error) if is_throttling(error.code()) && attempt < MAX_THROTTLE_RETRIES => {
+                    let delay = INITIAL_BACKOFF * 2u32.pow(attempt);
+                    #[cfg(feature = "logging")]
+                    warn!("SQS throttled the send, retrying in {:?}.", delay);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(error) => return Err(ObserverError::other(error)),
+            }
+        }
+    }
+}
+
+/// Returns `true` for the error codes SQS uses to signal throttling.
+fn is_throttling(code: Option<&str>) -> bool {
+    matches!(
+        code,
+        Some(
+            "ThrottlingException" | "RequestThrottled" | "AWS.SimpleQueueService.RequestThrottled"
+        )
+    )
+}
@@ -0,0 +1,160 @@
+//! An observer that forwards events to a local or remote syslog daemon.
+
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use std::io;
+use std::net::SocketAddr;
+#[cfg(unix)]
+use std::path::PathBuf;
+use tokio::net::UdpSocket;
+#[cfg(unix)]
+use tokio::net::UnixDatagram;
+
+/// The endpoint a [`SyslogObserver`] sends its messages to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyslogTransport {
+    /// A local Unix datagram socket, usually `/dev/log`.
+    #[cfg(unix)]
+    Unix(PathBuf),
+    /// A remote syslog server listening for UDP datagrams, usually on port 514.
+    Udp(SocketAddr),
+}
+
+/// The syslog facility, as defined by RFC 5424.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[allow(missing_docs)]
+pub enum Facility {
+    Kernel = 0,
+    #[default]
+    User = 1,
+    Mail = 2,
+    Daemon = 3,
+    Auth = 4,
+    Syslog = 5,
+    Lpr = 6,
+    News = 7,
+    Uucp = 8,
+    Cron = 9,
+    AuthPriv = 10,
+    Ftp = 11,
+    Local0 = 16,
+    Local1 = 17,
+    Local2 = 18,
+    Local3 = 19,
+    Local4 = 20,
+    Local5 = 21,
+    Local6 = 22,
+    Local7 = 23,
+}
+
+/// The syslog severity, as defined by RFC 5424.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[allow(missing_docs)]
+pub enum Severity {
+    Emergency = 0,
+    Alert = 1,
+    Critical = 2,
+    Error = 3,
+    Warning = 4,
+    Notice = 5,
+    #[default]
+    Informational = 6,
+    Debug = 7,
+}
+
+enum Socket {
+    #[cfg(unix)]
+    Unix(UnixDatagram),
+    Udp(UdpSocket),
+}
+
+/// An `Observer` that sends every event to syslog as a single datagram.
+///
+/// Each event is turned into a message by a user-supplied formatter; pass
+/// `ToString::to_string` to use the event's `Display` implementation. Messages are sent in
+/// the BSD syslog format without a timestamp, which the receiving daemon fills in, at the
+/// `user` facility and `informational` severity by default. Transport errors are returned and
+/// never abort the fan-out.
+pub struct SyslogObserver<T> {
+    socket: Socket,
+    formatter: Box<dyn Fn(&T) -> String + Send + Sync>,
+    facility: Facility,
+    severity: Severity,
+    tag: String,
+}
+
+impl<T> SyslogObserver<T> {
+    /// Connects a new `SyslogObserver` to `transport`, formatting events with `formatter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the socket cannot be created or connected.
+    pub async fn connect(
+        transport: SyslogTransport,
+        formatter: impl Fn(&T) -> String + Send + Sync + 'static,
+    ) -> io::Result<Self> {
+        let socket = match transport {
+            #[cfg(unix)]
+            SyslogTransport::Unix(path) => {
+                let socket = UnixDatagram::unbound()?;
+                socket.connect(path)?;
+                Socket::Unix(socket)
+            }
+            SyslogTransport::Udp(address) => {
+                let local: SocketAddr = if address.is_ipv4() {
+                    ([0, 0, 0, 0], 0).into()
+                } else {
+                    ([0u16; 8], 0).into()
+                };
+                let socket = UdpSocket::bind(local).await?;
+                socket.connect(address).await?;
+                Socket::Udp(socket)
+            }
+        };
+
+        Ok(Self {
+            socket,
+            formatter: Box::new(formatter),
+            facility: Facility::default(),
+            severity: Severity::default(),
+            tag: String::from(env!("CARGO_PKG_NAME")),
+        })
+    }
+
+    /// Sets the facility messages are logged under.
+    pub const fn with_facility(mut self, facility: Facility) -> Self {
+        self.facility = facility;
+        self
+    }
+
+    /// Sets the severity messages are logged at.
+    pub const fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Sets the tag, usually the application name, prefixed to every message.
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = tag.into();
+        self
+    }
+
+    fn render(&self, data: &T) -> String {
+        let priority = (self.facility as u8) * 8 + self.severity as u8;
+        format!("<{}>{}: {}", priority, self.tag, (self.formatter)(data))
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for SyslogObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        let message = self.render(data);
+        let result = match &self.socket {
+            #[cfg(unix)]
+            Socket::Unix(socket) => socket.send(message.as_bytes()).await,
+            Socket::Udp(socket) => socket.send(message.as_bytes()).await,
+        };
+        result?;
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+//! An observer that fans each event out to a fixed group of observers.
+
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// An `Observer` that forwards every event to several observers concurrently.
+///
+/// This groups related sinks behind a single attached observer, so they share one ID and
+/// one handle. The observers are updated with `futures::future::join_all`; once all of them
+/// have finished, the first error in the order they were given is returned, if any.
+pub struct TeeObserver<T> {
+    observers: Vec<Arc<dyn Observer<T>>>,
+}
+
+impl<T> TeeObserver<T> {
+    /// Creates a new `TeeObserver` forwarding to `observers`.
+    pub const fn new(observers: Vec<Arc<dyn Observer<T>>>) -> Self {
+        Self { observers }
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for TeeObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        let results =
+            futures::future::join_all(self.observers.iter().map(|observer| observer.update(data)))
+                .await;
+        results.into_iter().collect()
+    }
+}
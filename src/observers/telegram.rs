@@ -0,0 +1,80 @@
+//! An observer that sends events to a Telegram chat through the Bot API.
+
+use crate::observers::rate_limit::send_with_retry;
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::{Value, json};
+use std::time::Duration;
+
+const API_BASE_URL: &str = "https://api.telegram.org";
+
+/// An `Observer` that sends a Telegram message for every event.
+///
+/// The message text is produced by a user-supplied formatter and posted to the bot's
+/// `sendMessage` method. When Telegram answers `429 Too Many Requests`, the observer waits
+/// for the `parameters.retry_after` duration from the response and retries, up to three
+/// times. Other failures are returned as errors.
+pub struct TelegramObserver<T> {
+    client: Client,
+    url: String,
+    chat_id: String,
+    formatter: Box<dyn Fn(&T) -> String + Send + Sync>,
+}
+
+impl<T> TelegramObserver<T> {
+    /// Creates a new `TelegramObserver` sending messages to `chat_id` as the bot identified
+    /// by `token`.
+    ///
+    /// The chat id is either the numeric id of the chat or the `@username` of a channel.
+    pub fn new(
+        token: &str,
+        chat_id: impl Into<String>,
+        formatter: impl Fn(&T) -> String + Send + Sync + 'static,
+    ) -> Self {
+        Self::with_client(Client::new(), token, chat_id, formatter)
+    }
+
+    /// Creates a new `TelegramObserver` that sends its requests through `client`.
+    pub fn with_client(
+        client: Client,
+        token: &str,
+        chat_id: impl Into<String>,
+        formatter: impl Fn(&T) -> String + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            client,
+            url: format!("{API_BASE_URL}/bot{token}/sendMessage"),
+            chat_id: chat_id.into(),
+            formatter: Box::new(formatter),
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for TelegramObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        let body = json!({
+            "chat_id": self.chat_id,
+            "text": (self.formatter)(data),
+        });
+        send_with_retry(
+            || self.client.post(&self.url).json(&body).send(),
+            // The back-off is only reported in the JSON body, not in a header.
+            |response| async move {
+                let body = response.json::<Value>().await.ok()?;
+                body["parameters"]["retry_after"]
+                    .as_u64()
+                    .map(Duration::from_secs)
+            },
+            "Telegram",
+        )
+        .await
+        .map_err(redact)
+    }
+}
+
+/// Strips the request URL, which embeds the bot token, from a client error.
+fn redact(error: reqwest::Error) -> ObserverError {
+    ObserverError::other(error.without_url())
+}
@@ -0,0 +1,147 @@
+//! A decorator that limits how often the inner observer is notified.
+
+use crate::observers::{BackgroundErrors, update_in_background};
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+#[cfg(feature = "logging")]
+use tracing::debug;
+
+/// Selects which event of a throttling window reaches the inner observer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThrottleEdge {
+    /// The first event of a window is delivered immediately; the rest are dropped.
+    #[default]
+    Leading,
+    /// Events are held back and only the last one of a window is delivered when the
+    /// window closes.
+    Trailing,
+}
+
+struct ThrottleState<T> {
+    last_delivery: Option<Instant>,
+    pending: Option<T>,
+    scheduled: bool,
+}
+
+/// An `Observer` decorator that notifies the inner observer at most once per `interval`.
+///
+/// Events arriving while the current window is still open are dropped. With the default
+/// [`ThrottleEdge::Leading`] edge the first event of each window wins and is delivered
+/// inline; with [`ThrottleEdge::Trailing`] the last event wins and is delivered from a
+/// background Tokio task when the window closes. Errors and panics of such a delivery are
+/// logged, or passed to the handler set with [`ThrottleObserver::with_error_handler`].
+pub struct ThrottleObserver<T> {
+    inner: Arc<dyn Observer<T>>,
+    interval: Duration,
+    edge: ThrottleEdge,
+    state: Arc<Mutex<ThrottleState<T>>>,
+    errors: BackgroundErrors,
+}
+
+impl<T> ThrottleObserver<T> {
+    /// Creates a new `ThrottleObserver` delivering to `inner` at most once per `interval`,
+    /// using the leading edge.
+    pub fn new(inner: Arc<dyn Observer<T>>, interval: Duration) -> Self {
+        Self {
+            inner,
+            interval,
+            edge: ThrottleEdge::Leading,
+            state: Arc::new(Mutex::new(ThrottleState {
+                last_delivery: None,
+                pending: None,
+                scheduled: false,
+            })),
+            errors: BackgroundErrors::default(),
+        }
+    }
+
+    /// Sets which event of each window is delivered.
+    pub const fn with_edge(mut self, edge: ThrottleEdge) -> Self {
+        self.edge = edge;
+        self
+    }
+
+    /// Sets a handler receiving the errors of the inner observer when delivering from the background
+    /// task, a panic as
+    /// [`ObserverError::Panicked`]. Without one, they are only logged.
+    pub fn with_error_handler(
+        self,
+        handler: impl Fn(&ObserverError) + Send + Sync + 'static,
+    ) -> Self {
+        self.errors.set(handler);
+        self
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> ThrottleObserver<T> {
+    async fn update_leading(&self, data: &T) -> Result<(), ObserverError> {
+        let now = Instant::now();
+        let window_open = {
+            let mut state = self.state.lock();
+            let open = state
+                .last_delivery
+                .is_none_or(|last| now.duration_since(last) >= self.interval);
+            if open {
+                state.last_delivery = Some(now);
+            }
+            open
+        };
+
+        if window_open {
+            return self.inner.update(data).await;
+        }
+        #[cfg(feature = "logging")]
+        debug!("Throttled observer dropped an event.");
+        Ok(())
+    }
+
+    fn update_trailing(&self, data: &T) {
+        let schedule = {
+            let mut state = self.state.lock();
+            if state.pending.replace(data.clone()).is_some() {
+                #[cfg(feature = "logging")]
+                debug!("Throttled observer dropped an event.");
+            }
+            !std::mem::replace(&mut state.scheduled, true)
+        };
+        if !schedule {
+            return;
+        }
+
+        // The first event of a window opens it; the latest pending event is delivered when
+        // it closes.
+        let inner = Arc::clone(&self.inner);
+        let state = Arc::clone(&self.state);
+        let interval = self.interval;
+        let errors = self.errors.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(interval).await;
+            let pending = {
+                let mut state = state.lock();
+                state.scheduled = false;
+                state.last_delivery = Some(Instant::now());
+                state.pending.take()
+            };
+            if let Some(event) = pending {
+                update_in_background(&*inner, &event, "Throttled", &errors).await;
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync + 'static> Observer<T> for ThrottleObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        match self.edge {
+            ThrottleEdge::Leading => self.update_leading(data).await,
+            ThrottleEdge::Trailing => {
+                self.update_trailing(data);
+                Ok(())
+            }
+        }
+    }
+}
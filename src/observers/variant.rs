@@ -0,0 +1,49 @@
+//! A decorator that routes a single variant of an event enum to a typed observer.
+
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// The user-supplied function picking the payload of the wanted variant out of an event.
+type ExtractFn<T, U> = Box<dyn for<'a> Fn(&'a T) -> Option<&'a U> + Send + Sync>;
+
+/// An `Observer` that only fires for one variant of an event enum.
+///
+/// A single `Subject<MyEvent>` can carry several event types as enum variants. This
+/// decorator lets an `Observer<U>` register interest in just the variant holding a `U`:
+/// the extraction function returns the variant's payload, or `None` for every other
+/// variant, in which case the inner observer isn't called.
+///
+/// ```ignore
+/// let observer = VariantObserver::new(signup_observer, |event: &MyEvent| match event {
+///     MyEvent::UserSignedUp(signup) => Some(signup),
+///     _ => None,
+/// });
+/// ```
+pub struct VariantObserver<T, U> {
+    inner: Arc<dyn Observer<U>>,
+    extract: ExtractFn<T, U>,
+}
+
+impl<T, U> VariantObserver<T, U> {
+    /// Creates a new `VariantObserver` notifying `inner` with the payload `extract` finds.
+    pub fn new<F>(inner: Arc<dyn Observer<U>>, extract: F) -> Self
+    where
+        F: for<'a> Fn(&'a T) -> Option<&'a U> + Send + Sync + 'static,
+    {
+        Self {
+            inner,
+            extract: Box::new(extract),
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync, U: Send + Sync> Observer<T> for VariantObserver<T, U> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        match (self.extract)(data) {
+            Some(payload) => self.inner.update(payload).await,
+            None => Ok(()),
+        }
+    }
+}
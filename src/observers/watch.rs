@@ -0,0 +1,36 @@
+//! An observer backed by a `tokio::sync::watch` channel.
+
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use tokio::sync::watch;
+
+/// An `Observer` that publishes every event into a `tokio::sync::watch` channel.
+///
+/// A watch channel only ever holds the most recent value, so this observer implements
+/// "latest value wins" semantics: consumers that are slower than the event rate simply
+/// miss intermediate values and always observe the newest state. This is the desired
+/// behavior for state-like events such as a UI that renders the current status.
+pub struct WatchObserver<T> {
+    sender: watch::Sender<T>,
+}
+
+impl<T> WatchObserver<T> {
+    /// Creates a new `WatchObserver` that publishes events through `sender`.
+    pub const fn new(sender: watch::Sender<T>) -> Self {
+        Self { sender }
+    }
+
+    /// Creates a new receiver that observes the latest published value.
+    pub fn subscribe(&self) -> watch::Receiver<T> {
+        self.sender.subscribe()
+    }
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync> Observer<T> for WatchObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        // `send_replace` stores the value even when no receiver is currently subscribed.
+        self.sender.send_replace(data.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+//! An observer that posts events to a Slack or Discord incoming webhook.
+
+use crate::observers::rate_limit::{MAX_RETRY_AFTER, send_with_retry};
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Client, Response};
+use serde_json::{Value, json};
+use std::time::Duration;
+
+/// The webhook provider, which determines the shape of the JSON body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookFlavor {
+    /// A Slack incoming webhook, posting `{"text": ...}`.
+    Slack,
+    /// A Discord webhook, posting `{"content": ...}`.
+    Discord,
+}
+
+impl WebhookFlavor {
+    fn body(self, text: String) -> Value {
+        match self {
+            Self::Slack => json!({ "text": text }),
+            Self::Discord => json!({ "content": text }),
+        }
+    }
+}
+
+/// An `Observer` that posts a message to a chat webhook for every event.
+///
+/// The message text is produced by a user-supplied formatter and wrapped in the body the
+/// selected [`WebhookFlavor`] expects. When the provider answers `429 Too Many Requests`,
+/// the observer waits for the duration given by the `Retry-After` header and retries, up
+/// to three times. Other failures are returned as errors.
+pub struct WebhookObserver<T> {
+    client: Client,
+    url: String,
+    flavor: WebhookFlavor,
+    formatter: Box<dyn Fn(&T) -> String + Send + Sync>,
+}
+
+impl<T> WebhookObserver<T> {
+    /// Creates a new `WebhookObserver` posting the text produced by `formatter` to `url`.
+    pub fn new(
+        client: Client,
+        url: impl Into<String>,
+        flavor: WebhookFlavor,
+        formatter: impl Fn(&T) -> String + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            client,
+            url: url.into(),
+            flavor,
+            formatter: Box::new(formatter),
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Send + Sync> Observer<T> for WebhookObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        let body = self.flavor.body((self.formatter)(data));
+        send_with_retry(
+            || self.client.post(&self.url).json(&body).send(),
+            |response| async move { retry_after(&response) },
+            "Webhook",
+        )
+        .await
+        .map_err(redact)
+    }
+}
+
+/// Strips the request URL, which is the webhook's secret, from a client error.
+fn redact(error: reqwest::Error) -> ObserverError {
+    ObserverError::other(error.without_url())
+}
+
+/// Reads the `Retry-After` header of a rate-limited response, in (possibly fractional) seconds.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let seconds: f64 = response
+        .headers()
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    (seconds.is_finite() && seconds >= 0.0)
+        .then(|| Duration::from_secs_f64(seconds.min(MAX_RETRY_AFTER.as_secs_f64())))
+}
@@ -0,0 +1,80 @@
+//! Scoped attachment, detaching every observer attached within a scope when it ends.
+
+use crate::{Observer, ObserverHandle, Subject};
+use futures::future::BoxFuture;
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+/// Observers attached for the duration of a [`Subject::scope`] call.
+///
+/// Observers are still `'static`: a `Subject` can be cloned, and a notification that is
+/// already running keeps its own `Arc` to each observer, so an observer could outlive any
+/// data it borrowed. Without `unsafe` the closest guarantee is the one a scope gives:
+/// every observer attached through it is detached, with its `on_detach` hook awaited, by
+/// the time `scope` returns, so it receives no notification started afterwards. Resources
+/// owned by the caller are best shared through their own cheap handles, such as a cloned
+/// connection pool, rather than borrowed.
+pub struct Scope<T> {
+    subject: Subject<T>,
+    handles: Mutex<Vec<ObserverHandle<T>>>,
+}
+
+impl<T: Send + Sync + 'static> Scope<T> {
+    /// Attaches an `Observer` until the scope ends, returning its ID.
+    ///
+    /// Otherwise this behaves exactly like [`Subject::attach`].
+    pub async fn attach(&self, observer: Arc<dyn Observer<T>>) -> u64 {
+        let handle = self.subject.attach(observer).await;
+        let id = handle.id();
+        self.handles.lock().push(handle);
+        id
+    }
+}
+
+impl<T: Send + Sync + 'static> Subject<T> {
+    /// Runs `f` with a [`Scope`] whose observers are detached once `f` completes.
+    ///
+    /// The observers attached through the scope are detached explicitly, awaiting their
+    /// `on_detach` hooks, before this method returns. If the returned future is dropped
+    /// early or `f` panics, they are still detached as their handles are dropped.
+    ///
+    /// ```no_run
+    /// # use async_observer::{Observer, ObserverError, Subject};
+    /// # use async_trait::async_trait;
+    /// # use std::sync::Arc;
+    /// # struct AuditObserver;
+    /// # #[async_trait]
+    /// # impl Observer<String> for AuditObserver {
+    /// #     async fn update(&self, _data: &String) -> Result<(), ObserverError> {
+    /// #         Ok(())
+    /// #     }
+    /// # }
+    /// # async fn audit(subject: Subject<String>, event: String) {
+    /// // The future may only borrow the scope, so the subject is cloned into it.
+    /// let inner = subject.clone();
+    /// let count = subject
+    ///     .scope(move |scope| {
+    ///         Box::pin(async move {
+    ///             scope.attach(Arc::new(AuditObserver)).await;
+    ///             inner.notify(&event).await;
+    ///             inner.observer_count()
+    ///         })
+    ///     })
+    ///     .await;
+    /// assert_eq!(count, 1);
+    /// assert_eq!(subject.observer_count(), 0);
+    /// # }
+    /// ```
+    pub async fn scope<R>(&self, f: impl for<'s> FnOnce(&'s Scope<T>) -> BoxFuture<'s, R>) -> R {
+        let scope = Scope {
+            subject: self.clone(),
+            handles: Mutex::new(Vec::new()),
+        };
+        let result = f(&scope).await;
+        let handles = std::mem::take(&mut *scope.handles.lock());
+        for handle in handles {
+            self.detach(handle).await;
+        }
+        result
+    }
+}
@@ -0,0 +1,130 @@
+//! Adapters between `Subject`s and the `futures::Stream` ecosystem.
+
+use crate::{Observer, ObserverError, ObserverHandle, Subject};
+use async_trait::async_trait;
+use futures::channel::mpsc;
+#[cfg(feature = "tokio")]
+use futures::channel::oneshot;
+#[cfg(feature = "tokio")]
+use futures::future::{self, Either};
+use futures::{Stream, StreamExt};
+use std::pin::{Pin, pin};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+#[cfg(feature = "tokio")]
+use tokio::task::JoinHandle;
+
+/// An internal observer that forwards a clone of every event into an unbounded channel.
+struct StreamObserver<T> {
+    sender: mpsc::UnboundedSender<T>,
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync> Observer<T> for StreamObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        // The receiver is only dropped together with the stream, which also detaches us, so
+        // this can only fail while the two race.
+        self.sender
+            .unbounded_send(data.clone())
+            .map_err(|_| ObserverError::ChannelClosed)
+    }
+}
+
+/// A `Stream` yielding every event notified on a `Subject`.
+///
+/// Created by [`Subject::subscribe_stream`]. The stream owns the handle of its internal
+/// observer, so dropping the stream detaches it from the subject.
+pub struct SubjectStream<T> {
+    receiver: mpsc::UnboundedReceiver<T>,
+    _handle: ObserverHandle<T>,
+}
+
+// The stream never pin-projects into its fields, so it can always be moved.
+impl<T> Unpin for SubjectStream<T> {}
+
+impl<T> Stream for SubjectStream<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.receiver.poll_next_unpin(cx)
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> Subject<T> {
+    /// Subscribes to the `Subject` as a `Stream` of events.
+    ///
+    /// Every event notified after this call is cloned into an unbounded buffer and yielded
+    /// by the returned stream, so it composes with `StreamExt` combinators like `filter`
+    /// and `map`. Dropping the stream detaches the underlying observer.
+    pub async fn subscribe_stream(&self) -> SubjectStream<T> {
+        let (sender, receiver) = mpsc::unbounded();
+        let handle = self.attach(Arc::new(StreamObserver { sender })).await;
+        SubjectStream {
+            receiver,
+            _handle: handle,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Subject<T> {
+    /// Drives the `Subject` from a `Stream`, notifying all observers of every item.
+    ///
+    /// Items are delivered in order: each notification completes before the next item is
+    /// pulled from the stream. The returned future resolves once the stream ends.
+    pub async fn drive<S: Stream<Item = T>>(&self, stream: S) {
+        let mut stream = pin!(stream);
+        while let Some(item) = stream.next().await {
+            self.notify(&item).await;
+        }
+    }
+
+    /// Spawns a Tokio task that drives the `Subject` from a `Stream`.
+    ///
+    /// This is the background counterpart of [`Subject::drive`]. The returned
+    /// [`StreamDriver`] stops the task when [`StreamDriver::stop`] is called or when the
+    /// driver is dropped; a notification that is already in flight always completes.
+    #[cfg(feature = "tokio")]
+    pub fn spawn_drive<S>(&self, stream: S) -> StreamDriver
+    where
+        S: Stream<Item = T> + Send + 'static,
+    {
+        let subject = self.clone();
+        let (stop, mut stopped) = oneshot::channel();
+        let task = tokio::spawn(async move {
+            let mut stream = pin!(stream);
+            // Stops once a stop was requested, the driver was dropped, or the stream ended.
+            while let Either::Right((Some(item), _)) =
+                future::select(&mut stopped, stream.next()).await
+            {
+                subject.notify(&item).await;
+            }
+        });
+        StreamDriver { stop, task }
+    }
+}
+
+/// A handle to the background task created by [`Subject::spawn_drive`].
+///
+/// Dropping the driver stops the task once its in-flight notification completes.
+#[cfg(feature = "tokio")]
+pub struct StreamDriver {
+    stop: oneshot::Sender<()>,
+    task: JoinHandle<()>,
+}
+
+#[cfg(feature = "tokio")]
+impl StreamDriver {
+    /// Signals the task to stop and returns its `JoinHandle`.
+    ///
+    /// Awaiting the returned handle waits for the in-flight notification, if any, to finish.
+    pub fn stop(self) -> JoinHandle<()> {
+        let _ = self.stop.send(());
+        self.task
+    }
+
+    /// Returns `true` if the task has finished, either because the stream ended or
+    /// because it was stopped.
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+}
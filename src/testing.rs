@@ -0,0 +1,78 @@
+//! Utilities for testing code that wires observers to a `Subject`.
+
+use crate::{Observer, ObserverError};
+use async_trait::async_trait;
+use parking_lot::Mutex;
+#[cfg(feature = "tokio")]
+use std::time::Duration;
+
+/// An `Observer` that records every event it receives.
+///
+/// Attach it to a `Subject` and inspect [`MockObserver::received`] afterwards instead of
+/// hand-rolling a recording observer. It can also simulate a slow or broken sink: with
+/// `MockObserver::with_delay` each update sleeps first, and with
+/// [`MockObserver::failing`] each update returns an error. Events are recorded either way.
+pub struct MockObserver<T> {
+    received: Mutex<Vec<T>>,
+    #[cfg(feature = "tokio")]
+    delay: Option<Duration>,
+    failing: bool,
+}
+
+impl<T> MockObserver<T> {
+    /// Creates a new `MockObserver` that handles every event immediately and successfully.
+    pub fn new() -> Self {
+        Self {
+            received: Mutex::new(Vec::new()),
+            #[cfg(feature = "tokio")]
+            delay: None,
+            failing: false,
+        }
+    }
+
+    /// Makes each update sleep for `delay` before returning, using the Tokio timer.
+    #[cfg(feature = "tokio")]
+    pub const fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Makes each update return an `ObserverError::Other`.
+    pub const fn failing(mut self) -> Self {
+        self.failing = true;
+        self
+    }
+
+    /// Returns the number of times `update` has been called.
+    pub fn call_count(&self) -> usize {
+        self.received.lock().len()
+    }
+}
+
+impl<T: Clone> MockObserver<T> {
+    /// Returns the events received so far, in the order they arrived.
+    pub fn received(&self) -> Vec<T> {
+        self.received.lock().clone()
+    }
+}
+
+impl<T> Default for MockObserver<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync> Observer<T> for MockObserver<T> {
+    async fn update(&self, data: &T) -> Result<(), ObserverError> {
+        self.received.lock().push(data.clone());
+        #[cfg(feature = "tokio")]
+        if let Some(delay) = self.delay {
+            tokio::time::sleep(delay).await;
+        }
+        if self.failing {
+            return Err(ObserverError::other("mock observer failure"));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+#![cfg(all(feature = "tokio", feature = "testing"))]
+
+use async_observer::observers::buffered::BufferedObserver;
+use async_observer::observers::circuit::{CircuitBreakerObserver, CircuitState};
+use async_observer::observers::coalesce::CoalescingObserver;
+use async_observer::observers::debounce::DebounceObserver;
+use async_observer::observers::throttle::{ThrottleEdge, ThrottleObserver};
+use async_observer::testing::MockObserver;
+use async_observer::{Observer, ObserverError};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+struct Panicker;
+
+#[async_trait]
+impl Observer<u32> for Panicker {
+    async fn update(&self, _data: &u32) -> Result<(), ObserverError> {
+        panic!("observer bug");
+    }
+}
+
+/// Fails until told to recover.
+#[derive(Default)]
+struct Flaky {
+    recovered: AtomicBool,
+}
+
+#[async_trait]
+impl Observer<u32> for Flaky {
+    async fn update(&self, _data: &u32) -> Result<(), ObserverError> {
+        if self.recovered.load(Ordering::SeqCst) {
+            Ok(())
+        } else {
+            Err(ObserverError::other("not yet"))
+        }
+    }
+}
+
+#[tokio::test]
+async fn background_failures_reach_the_error_handler() {
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let failing = BufferedObserver::new(Arc::new(MockObserver::new().failing()), 4)
+        .with_error_handler({
+            let sender = sender.clone();
+            move |error| sender.send(error.to_string()).unwrap()
+        });
+    let panicking = BufferedObserver::new(Arc::new(Panicker), 4)
+        .with_error_handler(move |error| sender.send(error.to_string()).unwrap());
+
+    failing.update(&1).await.unwrap();
+    assert!(
+        receiver
+            .recv()
+            .await
+            .unwrap()
+            .contains("mock observer failure")
+    );
+    panicking.update(&2).await.unwrap();
+    assert_eq!(
+        receiver.recv().await.unwrap(),
+        ObserverError::Panicked.to_string()
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn circuit_opens_and_reopens_after_a_failed_trial() {
+    let inner = Arc::new(MockObserver::new().failing());
+    let breaker = CircuitBreakerObserver::new(inner.clone(), 2, Duration::from_secs(10));
+
+    assert!(breaker.update(&1).await.is_err());
+    assert_eq!(breaker.state(), CircuitState::Closed);
+    assert!(breaker.update(&2).await.is_err());
+    assert_eq!(breaker.state(), CircuitState::Open);
+
+    // Skipped without reaching the inner observer until the cooldown has elapsed.
+    tokio::time::advance(Duration::from_secs(9)).await;
+    assert!(breaker.update(&3).await.is_ok());
+    assert_eq!(inner.call_count(), 2);
+
+    tokio::time::advance(Duration::from_secs(1)).await;
+    assert!(breaker.update(&4).await.is_err());
+    assert_eq!(inner.received(), vec![1, 2, 4]);
+    assert_eq!(breaker.state(), CircuitState::Open);
+
+    // The failed trial starts a fresh cooldown.
+    tokio::time::advance(Duration::from_secs(9)).await;
+    assert!(breaker.update(&5).await.is_ok());
+    assert_eq!(inner.call_count(), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn circuit_closes_after_a_successful_trial() {
+    let inner = Arc::new(Flaky::default());
+    let breaker = CircuitBreakerObserver::new(inner.clone(), 1, Duration::from_secs(10));
+    assert!(breaker.update(&1).await.is_err());
+    assert_eq!(breaker.state(), CircuitState::Open);
+
+    inner.recovered.store(true, Ordering::SeqCst);
+    tokio::time::advance(Duration::from_secs(10)).await;
+    assert!(breaker.update(&2).await.is_ok());
+    assert_eq!(breaker.state(), CircuitState::Closed);
+
+    // The failure count starts over once closed.
+    inner.recovered.store(false, Ordering::SeqCst);
+    assert!(breaker.update(&3).await.is_err());
+    assert_eq!(breaker.state(), CircuitState::Open);
+}
+
+#[tokio::test(start_paused = true)]
+async fn cancelled_trial_reopens_the_circuit() {
+    let inner = Arc::new(
+        MockObserver::new()
+            .failing()
+            .with_delay(Duration::from_secs(5)),
+    );
+    let breaker = CircuitBreakerObserver::new(inner.clone(), 1, Duration::from_secs(10));
+    assert!(breaker.update(&1).await.is_err());
+    tokio::time::advance(Duration::from_secs(10)).await;
+
+    // Events arriving while the trial runs are skipped.
+    let mut trial = breaker.update(&2);
+    assert!(futures::poll!(&mut trial).is_pending());
+    assert_eq!(breaker.state(), CircuitState::HalfOpen);
+    assert!(breaker.update(&3).await.is_ok());
+    assert_eq!(inner.received(), vec![1, 2]);
+
+    drop(trial);
+    assert_eq!(breaker.state(), CircuitState::Open);
+    tokio::time::advance(Duration::from_secs(10)).await;
+    assert!(breaker.update(&4).await.is_err());
+    assert_eq!(inner.received(), vec![1, 2, 4]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn leading_throttle_delivers_the_first_event_of_each_window() {
+    let inner = Arc::new(MockObserver::new());
+    let throttle = ThrottleObserver::new(inner.clone(), Duration::from_secs(1));
+
+    throttle.update(&1).await.unwrap();
+    throttle.update(&2).await.unwrap();
+    tokio::time::advance(Duration::from_millis(999)).await;
+    throttle.update(&3).await.unwrap();
+    tokio::time::advance(Duration::from_millis(1)).await;
+    throttle.update(&4).await.unwrap();
+    throttle.update(&5).await.unwrap();
+
+    assert_eq!(inner.received(), vec![1, 4]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn trailing_throttle_delivers_the_last_event_when_the_window_closes() {
+    let inner = Arc::new(MockObserver::new());
+    let throttle = ThrottleObserver::new(inner.clone(), Duration::from_secs(1))
+        .with_edge(ThrottleEdge::Trailing);
+
+    throttle.update(&1).await.unwrap();
+    throttle.update(&2).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(999)).await;
+    throttle.update(&3).await.unwrap();
+    assert_eq!(inner.call_count(), 0);
+
+    tokio::time::sleep(Duration::from_millis(2)).await;
+    assert_eq!(inner.received(), vec![3]);
+
+    // The next event opens a new window.
+    throttle.update(&4).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(1001)).await;
+    assert_eq!(inner.received(), vec![3, 4]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn debounce_delivers_the_last_event_of_each_burst() {
+    let inner = Arc::new(MockObserver::new());
+    let debounce = DebounceObserver::new(inner.clone(), Duration::from_secs(1));
+
+    // Each event restarts the quiet period, so nothing is delivered mid-burst.
+    for event in 1..=3 {
+        debounce.update(&event).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(900)).await;
+    }
+    assert_eq!(inner.call_count(), 0);
+    tokio::time::sleep(Duration::from_millis(101)).await;
+    assert_eq!(inner.received(), vec![3]);
+
+    debounce.update(&4).await.unwrap();
+    debounce.update(&5).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(1001)).await;
+    assert_eq!(inner.received(), vec![3, 5]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn debounce_flushes_the_pending_event_when_dropped() {
+    let inner = Arc::new(MockObserver::new());
+    let debounce = DebounceObserver::new(inner.clone(), Duration::from_secs(1));
+    debounce.update(&1).await.unwrap();
+    drop(debounce);
+
+    tokio::time::sleep(Duration::from_millis(1)).await;
+    assert_eq!(inner.received(), vec![1]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn coalescing_keeps_the_latest_event_per_key_while_busy() {
+    let inner = Arc::new(MockObserver::new().with_delay(Duration::from_secs(1)));
+    let coalescing = CoalescingObserver::new(inner.clone(), |event: &(char, u32)| event.0);
+
+    coalescing.update(&('a', 1)).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(1)).await;
+    // The worker is busy with the first event, so these merge by key.
+    for event in [('b', 1), ('a', 2), ('b', 2), ('a', 3)] {
+        coalescing.update(&event).await.unwrap();
+    }
+    tokio::time::sleep(Duration::from_secs(3)).await;
+
+    // Keys are delivered in the order they first arrived.
+    assert_eq!(inner.received(), vec![('a', 1), ('b', 2), ('a', 3)]);
+}
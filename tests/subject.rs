@@ -0,0 +1,424 @@
+use async_observer::{LifecycleKind, Observer, ObserverError, Subject};
+use async_trait::async_trait;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Counts the events it receives. Clones share the count.
+#[derive(Default, Clone)]
+struct Counter(Arc<AtomicUsize>);
+
+impl Counter {
+    fn count(&self) -> usize {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl Observer<u32> for Counter {
+    async fn update(&self, _data: &u32) -> Result<(), ObserverError> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+/// Panics on every event.
+struct Panicker;
+
+#[async_trait]
+impl Observer<u32> for Panicker {
+    async fn update(&self, _data: &u32) -> Result<(), ObserverError> {
+        panic!("deliberate panic");
+    }
+}
+
+/// Counts the events it receives after sleeping for a second on each.
+#[derive(Default, Clone)]
+struct Slow(Counter);
+
+#[async_trait]
+impl Observer<u32> for Slow {
+    async fn update(&self, data: &u32) -> Result<(), ObserverError> {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        self.0.update(data).await
+    }
+}
+
+/// Starts a notification on its own task and waits until it is in flight.
+async fn notify_in_background(subject: &Subject<u32>) -> tokio::task::JoinHandle<()> {
+    let before = subject.notify_in_flight();
+    let notifying = subject.clone();
+    let task = tokio::spawn(async move { notifying.notify(&1).await });
+    while subject.notify_in_flight() == before {
+        tokio::task::yield_now().await;
+    }
+    task
+}
+
+/// Records whether its `on_detach` hook has completed.
+#[derive(Default, Clone)]
+struct DetachTracker(Arc<AtomicBool>);
+
+#[async_trait]
+impl Observer<u32> for DetachTracker {
+    async fn update(&self, _data: &u32) -> Result<(), ObserverError> {
+        Ok(())
+    }
+
+    async fn on_detach(&self) {
+        tokio::task::yield_now().await;
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[tokio::test]
+async fn panicking_observer_does_not_stop_siblings() {
+    let subject = Subject::new();
+    let first = Arc::new(Counter::default());
+    let second = Arc::new(Counter::default());
+    let _first = subject.attach(first.clone()).await;
+    let panicking = subject.attach(Arc::new(Panicker)).await;
+    let _second = subject.attach(second.clone()).await;
+
+    let report = subject.notify_acked(&1).await;
+
+    assert_eq!(first.count(), 1);
+    assert_eq!(second.count(), 1);
+    assert!(matches!(
+        report.result(panicking.id()),
+        Some(Err(ObserverError::Panicked))
+    ));
+    assert_eq!(report.succeeded(), 2);
+}
+
+#[tokio::test]
+async fn handles_are_no_ops_after_detach_all() {
+    let subject = Subject::new();
+    let detached = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&detached);
+    subject.on_lifecycle(move |event| {
+        if event.kind == LifecycleKind::Detached {
+            sink.lock().unwrap().push(event.id);
+        }
+    });
+    let first = subject.attach(Arc::new(Counter::default())).await;
+    let second = subject.attach(Arc::new(Counter::default())).await;
+    let ids = vec![first.id(), second.id()];
+
+    assert_eq!(subject.detach_all().await, 2);
+    assert_eq!(subject.observer_count(), 0);
+    drop(first);
+    drop(second);
+
+    // Only `detach_all` reported the removals; the dropped handles found nothing to detach.
+    assert_eq!(*detached.lock().unwrap(), ids);
+    assert_eq!(subject.observer_count(), 0);
+}
+
+#[tokio::test]
+async fn weak_observer_is_pruned_once_dropped() {
+    let subject = Subject::new();
+    let counter = Counter::default();
+    let observer: Arc<dyn Observer<u32>> = Arc::new(counter.clone());
+    let _handle = subject
+        .attach_weak(Arc::downgrade(&observer))
+        .await
+        .unwrap();
+
+    subject.notify(&1).await;
+    assert_eq!(counter.count(), 1);
+    assert_eq!(subject.observer_count(), 1);
+
+    drop(observer);
+    subject.notify(&2).await;
+    assert_eq!(counter.count(), 1);
+    assert_eq!(subject.observer_count(), 0);
+}
+
+#[tokio::test]
+async fn taken_observer_can_be_attached_elsewhere() {
+    let source = Subject::new();
+    let target = Subject::new();
+    let counter = Counter::default();
+    let handle = source.attach(Arc::new(counter.clone())).await;
+
+    let observer = source.take(handle.id()).unwrap();
+    assert_eq!(source.observer_count(), 0);
+    let _moved = target.attach(observer).await;
+
+    source.notify(&1).await;
+    assert_eq!(counter.count(), 0);
+    target.notify(&2).await;
+    assert_eq!(counter.count(), 1);
+}
+
+#[tokio::test]
+async fn retain_removes_exactly_the_rejected_observers() {
+    let subject = Subject::new();
+    let mut handles = Vec::new();
+    for _ in 0..4 {
+        handles.push(subject.attach(Arc::new(Counter::default())).await);
+    }
+    let kept: Vec<u64> = handles
+        .iter()
+        .step_by(2)
+        .map(|handle| handle.id())
+        .collect();
+
+    let removed = subject.retain(|id, _| kept.contains(&id));
+
+    assert_eq!(removed, 2);
+    assert_eq!(subject.observer_ids(), kept);
+}
+
+#[tokio::test]
+async fn self_removing_observer_fires_once() {
+    let subject = Subject::new();
+    let counter = Counter::default();
+    let _other = subject.attach(Arc::new(Counter::default())).await;
+    let id = subject
+        .attach_self_removing(Arc::new(counter.clone()))
+        .await;
+    assert_eq!(subject.observer_count(), 2);
+
+    subject.notify(&1).await;
+    subject.notify(&2).await;
+
+    assert_eq!(counter.count(), 1);
+    assert_eq!(subject.observer_count(), 1);
+    assert!(!subject.observer_ids().contains(&id));
+}
+
+#[tokio::test]
+async fn detach_now_leaves_nothing_for_drop() {
+    let subject = Subject::new();
+    let detached = Arc::new(AtomicUsize::new(0));
+    let sink = Arc::clone(&detached);
+    subject.on_lifecycle(move |event| {
+        if event.kind == LifecycleKind::Detached {
+            sink.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+    let _other = subject.attach(Arc::new(Counter::default())).await;
+    let mut handle = subject.attach(Arc::new(Counter::default())).await;
+
+    assert!(handle.detach_now());
+    assert!(!handle.detach_now());
+    drop(handle);
+
+    assert_eq!(detached.load(Ordering::SeqCst), 1);
+    assert_eq!(subject.observer_count(), 1);
+}
+
+#[tokio::test]
+async fn scope_detaches_its_observers_before_returning() {
+    let subject = Subject::new();
+    let tracker = DetachTracker::default();
+    let observer = tracker.clone();
+    let inner = subject.clone();
+
+    let count = subject
+        .scope(move |scope| {
+            Box::pin(async move {
+                scope.attach(Arc::new(observer)).await;
+                inner.observer_count()
+            })
+        })
+        .await;
+
+    assert_eq!(count, 1);
+    assert_eq!(subject.observer_count(), 0);
+    assert!(tracker.0.load(Ordering::SeqCst));
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn notify_all_or_timeout_accepts_an_unbounded_budget() {
+    let subject = Subject::new();
+    let counter = Counter::default();
+    let handle = subject.attach(Arc::new(counter.clone())).await;
+
+    let report = subject
+        .notify_all_or_timeout(&1, std::time::Duration::MAX)
+        .await;
+
+    assert_eq!(report.completed, vec![handle.id()]);
+    assert!(report.cancelled.is_empty());
+    assert_eq!(counter.count(), 1);
+}
+
+#[tokio::test]
+async fn stale_handle_does_not_detach_a_reused_id() {
+    let subject = Subject::new();
+    let first = subject
+        .attach_with_id(7, Arc::new(Counter::default()))
+        .await
+        .unwrap();
+    let second = subject
+        .attach_with_id(8, Arc::new(Counter::default()))
+        .await
+        .unwrap();
+    subject.take(7).unwrap();
+    subject.take(8).unwrap();
+    let counter = Counter::default();
+    let _reused = subject
+        .attach_with_id(7, Arc::new(counter.clone()))
+        .await
+        .unwrap();
+    let _also_reused = subject
+        .attach_with_id(8, Arc::new(Counter::default()))
+        .await
+        .unwrap();
+
+    drop(first);
+    assert!(!subject.detach(second).await);
+
+    assert_eq!(subject.observer_ids(), vec![7, 8]);
+    subject.notify(&1).await;
+    assert_eq!(counter.count(), 1);
+}
+
+#[tokio::test]
+async fn attach_unique_replaces_the_previous_observer() {
+    let subject = Subject::new();
+    let old = Counter::default();
+    let new = Counter::default();
+    let (_old, replaced) = subject.attach_unique("sink", Arc::new(old.clone())).await;
+    assert!(!replaced);
+    let (_new, replaced) = subject.attach_unique("sink", Arc::new(new.clone())).await;
+    assert!(replaced);
+
+    subject.notify(&1).await;
+
+    assert_eq!(subject.observer_count(), 1);
+    assert_eq!((old.count(), new.count()), (0, 1));
+}
+
+#[tokio::test]
+async fn lifecycle_handler_can_query_the_subject() {
+    let subject = Subject::new();
+    let counts = Arc::new(Mutex::new(Vec::new()));
+    let (inner, sink) = (subject.clone(), Arc::clone(&counts));
+    subject.on_lifecycle(move |event| {
+        if event.kind == LifecycleKind::Detached {
+            sink.lock().unwrap().push(inner.observer_count());
+        }
+    });
+    let explicit = subject.attach(Arc::new(Counter::default())).await;
+    let taken = subject.attach(Arc::new(Counter::default())).await;
+    let _rejected = subject.attach(Arc::new(Counter::default())).await;
+    let weak: Arc<dyn Observer<u32>> = Arc::new(Counter::default());
+    let _weak = subject.attach_weak(Arc::downgrade(&weak)).await.unwrap();
+
+    assert!(subject.detach(explicit).await);
+    subject.take(taken.id()).unwrap();
+    assert_eq!(subject.retain(|_, _| true), 0);
+    drop(weak);
+    subject.notify(&1).await;
+    assert_eq!(subject.retain(|_, _| false), 1);
+
+    assert_eq!(*counts.lock().unwrap(), vec![3, 2, 1, 0]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn shutdown_rejects_new_notifications_and_awaits_in_flight_ones() {
+    let subject = Subject::new();
+    let slow = Slow::default();
+    let _slow = subject.attach(Arc::new(slow.clone())).await;
+    let task = notify_in_background(&subject).await;
+
+    let shutdown = subject.shutdown();
+    futures::pin_mut!(shutdown);
+    assert!(futures::poll!(&mut shutdown).is_pending());
+    assert!(subject.is_shutting_down());
+    subject.notify(&2).await;
+    assert_eq!(subject.notify_in_flight(), 1);
+
+    shutdown.await;
+    assert_eq!(slow.0.count(), 1);
+    assert_eq!(subject.notify_in_flight(), 0);
+    task.await.unwrap();
+
+    // Observers stay attached but no longer receive events.
+    subject.notify(&3).await;
+    assert_eq!(subject.observer_count(), 1);
+    assert_eq!(slow.0.count(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn wait_idle_wakes_once_in_flight_notifications_finish() {
+    let subject = Subject::new();
+    subject.wait_idle().await;
+
+    let slow = Slow::default();
+    let _slow = subject.attach(Arc::new(slow.clone())).await;
+    let first = notify_in_background(&subject).await;
+    let second = notify_in_background(&subject).await;
+    assert_eq!(subject.notify_in_flight(), 2);
+
+    subject.wait_idle().await;
+    assert_eq!(subject.notify_in_flight(), 0);
+    assert_eq!(slow.0.count(), 2);
+    first.await.unwrap();
+    second.await.unwrap();
+
+    // Unlike shutdown, waiting for idle leaves the subject accepting notifications.
+    subject.notify(&3).await;
+    assert_eq!(slow.0.count(), 3);
+}
+
+#[cfg(all(feature = "tokio", feature = "testing"))]
+#[tokio::test(start_paused = true)]
+async fn notify_by_deadline_reports_and_skips_late_observers() {
+    use async_observer::testing::MockObserver;
+
+    let subject = Subject::new();
+    let fast = Arc::new(MockObserver::new());
+    let slow = Arc::new(MockObserver::new().with_delay(Duration::from_secs(5)));
+    let fast_handle = subject.attach(fast.clone()).await;
+    let slow_handle = subject.attach(slow.clone()).await;
+
+    let start = tokio::time::Instant::now();
+    let deadline = (start + Duration::from_secs(1)).into_std();
+    let report = subject.notify_by_deadline(&1, deadline).await;
+    assert_eq!(report.completed, vec![fast_handle.id()]);
+    assert_eq!(report.cancelled, vec![slow_handle.id()]);
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+
+    // Past the deadline, no observer is started at all.
+    let report = subject.notify_by_deadline(&2, deadline).await;
+    assert!(report.completed.is_empty());
+    assert_eq!(report.cancelled.len(), 2);
+    assert_eq!((fast.received(), slow.received()), (vec![1], vec![1]));
+}
+
+#[cfg(all(feature = "tokio", feature = "testing"))]
+#[tokio::test(start_paused = true)]
+async fn notify_cancellable_reports_and_skips_cancelled_observers() {
+    use async_observer::testing::MockObserver;
+    use tokio_util::sync::CancellationToken;
+
+    let subject = Subject::new();
+    let fast = Arc::new(MockObserver::new());
+    let slow = Arc::new(MockObserver::new().with_delay(Duration::from_secs(5)));
+    let fast_handle = subject.attach(fast.clone()).await;
+    let slow_handle = subject.attach(slow.clone()).await;
+
+    let token = CancellationToken::new();
+    let canceller = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        canceller.cancel();
+    });
+    let start = tokio::time::Instant::now();
+    let report = subject.notify_cancellable(&1, token.clone()).await;
+    assert_eq!(report.completed, vec![fast_handle.id()]);
+    assert_eq!(report.cancelled, vec![slow_handle.id()]);
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+
+    // An already cancelled token notifies no one.
+    let report = subject.notify_cancellable(&2, token).await;
+    assert!(report.completed.is_empty());
+    assert_eq!(report.cancelled.len(), 2);
+    assert_eq!((fast.received(), slow.received()), (vec![1], vec![1]));
+}